@@ -16,6 +16,63 @@ use nodes::JsonObject;
 use nodes::ToInt;
 use nodes::Sum;
 use nodes::StringContains;
+use nodes::ParseDate;
+use nodes::FormatDate;
+use nodes::DateTimezone;
+use nodes::DateDiff;
+use nodes::DateTruncate;
+use nodes::CsvParse;
+use nodes::CsvStringify;
+use nodes::FileIn;
+use nodes::FileOut;
+use nodes::ListFiles;
+use nodes::JsonQuery;
+use nodes::JsonValues;
+use nodes::JsonPluck;
+use nodes::JsonOmit;
+use nodes::JsonMerge;
+use nodes::JsonMergePatch;
+use nodes::JsonPatch;
+use nodes::JsonDiff;
+use nodes::JsonFlatten;
+use nodes::JsonUnflatten;
+use nodes::NdjsonParse;
+use nodes::NdjsonStringify;
+use nodes::YamlParse;
+use nodes::YamlStringify;
+use nodes::TomlParse;
+use nodes::TomlStringify;
+use nodes::XmlParse;
+use nodes::HtmlSelect;
+use nodes::JsonValidate;
+use nodes::patterns;
+use nodes::RegexFilter;
+use nodes::RegexReplace;
+use nodes::RegexExtract;
+use nodes::StringTrim;
+use nodes::StringPrepend;
+use nodes::StringAppend;
+use nodes::StringCase;
+use nodes::StringSlugify;
+use nodes::Split;
+use nodes::SplitEach;
+use nodes::Join;
+use nodes::Sort;
+use nodes::TakeDrop;
+use nodes::Slice;
+use nodes::Chunk;
+use nodes::Window;
+use nodes::Reverse;
+use nodes::SetOperation;
+use nodes::Unique;
+use nodes::CountDistinct;
+use nodes::Shuffle;
+use nodes::Sample;
+use nodes::SampleFraction;
+use nodes::Arithmetic;
+use nodes::Aggregate;
+use nodes::GroupBy;
+use nodes::JoinRecords;
 
 pub fn build(id: i64, type_: String) -> Option<Rc<RefCell<Node>>> {
     match type_.as_str() {
@@ -47,6 +104,10 @@ pub fn build(id: i64, type_: String) -> Option<Rc<RefCell<Node>>> {
             return Some(Rc::new(RefCell::new(JsonStringify {
                 id: id,
                 input: None,
+                indent: 0,
+                sort_keys: false,
+                ascii: false,
+                canonical: false,
             })));
         }
         "json-keys" => {
@@ -60,6 +121,8 @@ pub fn build(id: i64, type_: String) -> Option<Rc<RefCell<Node>>> {
                 id: id,
                 keys_input: None,
                 values_input: None,
+                infer: false,
+                mismatch: "truncate".to_string(),
             })));
         }
         "to-int" => {
@@ -79,6 +142,471 @@ pub fn build(id: i64, type_: String) -> Option<Rc<RefCell<Node>>> {
                 id: id,
                 input: None,
                 value: "".to_string(),
+                terms: String::from("single"),
+                position: String::from("anywhere"),
+                case_insensitive: false,
+                whole_word: false,
+                invert: false,
+            })));
+        }
+        "parse-date" => {
+            return Some(Rc::new(RefCell::new(ParseDate {
+                id: id,
+                input: None,
+                format: "rfc3339".to_string(),
+            })));
+        }
+        "format-date" => {
+            return Some(Rc::new(RefCell::new(FormatDate {
+                id: id,
+                input: None,
+                format: "rfc3339".to_string(),
+            })));
+        }
+        "date-timezone" => {
+            return Some(Rc::new(RefCell::new(DateTimezone {
+                id: id,
+                input: None,
+                offset: "+00:00".to_string(),
+            })));
+        }
+        "date-diff" => {
+            return Some(Rc::new(RefCell::new(DateDiff {
+                id: id,
+                start_input: None,
+                end_input: None,
+                unit: "second".to_string(),
+            })));
+        }
+        "date-truncate" => {
+            return Some(Rc::new(RefCell::new(DateTruncate {
+                id: id,
+                input: None,
+                unit: "day".to_string(),
+            })));
+        }
+        "csv-parse" => {
+            return Some(Rc::new(RefCell::new(CsvParse {
+                id: id,
+                input: None,
+                delimiter: ",".to_string(),
+                headers: true,
+                output: "table".to_string(),
+            })));
+        }
+        "csv-stringify" => {
+            return Some(Rc::new(RefCell::new(CsvStringify {
+                id: id,
+                input: None,
+                delimiter: ",".to_string(),
+                headers: true,
+            })));
+        }
+        "file-in" => {
+            return Some(Rc::new(RefCell::new(FileIn {
+                id: id,
+                input: None,
+                path: "".to_string(),
+            })));
+        }
+        "file-out" => {
+            return Some(Rc::new(RefCell::new(FileOut {
+                id: id,
+                input: None,
+                path: "".to_string(),
+                mode: "overwrite".to_string(),
+                create_dirs: true,
+            })));
+        }
+        "list-files" => {
+            return Some(Rc::new(RefCell::new(ListFiles {
+                id: id,
+                path: ".".to_string(),
+                recursive: true,
+                include: "".to_string(),
+                exclude: "".to_string(),
+                follow_symlinks: false,
+                output: "records".to_string(),
+            })));
+        }
+        "json-query" => {
+            return Some(Rc::new(RefCell::new(JsonQuery {
+                id: id,
+                input: None,
+                path: "$".to_string(),
+                output: "json".to_string(),
+            })));
+        }
+        "json-values" => {
+            return Some(Rc::new(RefCell::new(JsonValues {
+                id: id,
+                input: None,
+            })));
+        }
+        "json-pluck" => {
+            return Some(Rc::new(RefCell::new(JsonPluck {
+                id: id,
+                input: None,
+                path: "".to_string(),
+                output: "json".to_string(),
+            })));
+        }
+        "json-omit" => {
+            return Some(Rc::new(RefCell::new(JsonOmit {
+                id: id,
+                input: None,
+                keys: "".to_string(),
+            })));
+        }
+        "json-merge" => {
+            return Some(Rc::new(RefCell::new(JsonMerge {
+                id: id,
+                base_input: None,
+                overlay_input: None,
+                arrays: "replace".to_string(),
+            })));
+        }
+        "json-merge-patch" => {
+            return Some(Rc::new(RefCell::new(JsonMergePatch {
+                id: id,
+                target_input: None,
+                patch_input: None,
+            })));
+        }
+        "json-patch" => {
+            return Some(Rc::new(RefCell::new(JsonPatch {
+                id: id,
+                document_input: None,
+                patch_input: None,
+            })));
+        }
+        "json-diff" => {
+            return Some(Rc::new(RefCell::new(JsonDiff {
+                id: id,
+                from_input: None,
+                to_input: None,
+            })));
+        }
+        "json-flatten" => {
+            return Some(Rc::new(RefCell::new(JsonFlatten {
+                id: id,
+                input: None,
+                separator: ".".to_string(),
+                arrays: "brackets".to_string(),
+            })));
+        }
+        "json-unflatten" => {
+            return Some(Rc::new(RefCell::new(JsonUnflatten {
+                id: id,
+                input: None,
+                separator: ".".to_string(),
+                arrays: "brackets".to_string(),
+            })));
+        }
+        "ndjson-parse" => {
+            return Some(Rc::new(RefCell::new(NdjsonParse {
+                id: id,
+                input: None,
+                skip_invalid: false,
+            })));
+        }
+        "ndjson-stringify" => {
+            return Some(Rc::new(RefCell::new(NdjsonStringify {
+                id: id,
+                input: None,
+            })));
+        }
+        "yaml-parse" => {
+            return Some(Rc::new(RefCell::new(YamlParse {
+                id: id,
+                input: None,
+                multi_document: false,
+            })));
+        }
+        "yaml-stringify" => {
+            return Some(Rc::new(RefCell::new(YamlStringify {
+                id: id,
+                input: None,
+                multi_document: false,
+            })));
+        }
+        "toml-parse" => {
+            return Some(Rc::new(RefCell::new(TomlParse {
+                id: id,
+                input: None,
+            })));
+        }
+        "toml-stringify" => {
+            return Some(Rc::new(RefCell::new(TomlStringify {
+                id: id,
+                input: None,
+            })));
+        }
+        "xml-parse" => {
+            return Some(Rc::new(RefCell::new(XmlParse {
+                id: id,
+                input: None,
+            })));
+        }
+        "html-select" => {
+            return Some(Rc::new(RefCell::new(HtmlSelect {
+                id: id,
+                input: None,
+                selector: String::new(),
+                attribute: String::new(),
+            })));
+        }
+        "json-validate" => {
+            return Some(Rc::new(RefCell::new(JsonValidate {
+                id: id,
+                input: None,
+                schema_input: None,
+                schema: String::new(),
+                on_invalid: String::from("error"),
+            })));
+        }
+        "regex-filter" => {
+            return Some(Rc::new(RefCell::new(RegexFilter {
+                id: id,
+                input: None,
+                pattern: String::new(),
+                regex: patterns::compile(""),
+                invert: false,
+            })));
+        }
+        "regex-replace" => {
+            return Some(Rc::new(RefCell::new(RegexReplace {
+                id: id,
+                input: None,
+                pattern: String::new(),
+                regex: patterns::compile(""),
+                replacement: String::new(),
+                all: true,
+            })));
+        }
+        "regex-extract" => {
+            return Some(Rc::new(RefCell::new(RegexExtract {
+                id: id,
+                input: None,
+                pattern: String::new(),
+                regex: patterns::compile(""),
+                output: String::from("json"),
+            })));
+        }
+        "string-trim" => {
+            return Some(Rc::new(RefCell::new(StringTrim {
+                id: id,
+                input: None,
+                side: String::from("both"),
+                characters: String::new(),
+            })));
+        }
+        "string-prepend" => {
+            return Some(Rc::new(RefCell::new(StringPrepend {
+                id: id,
+                input: None,
+                value: String::new(),
+            })));
+        }
+        "string-append" => {
+            return Some(Rc::new(RefCell::new(StringAppend {
+                id: id,
+                input: None,
+                value: String::new(),
+            })));
+        }
+        "string-case" => {
+            return Some(Rc::new(RefCell::new(StringCase {
+                id: id,
+                input: None,
+                case: String::from("lower"),
+            })));
+        }
+        "string-slugify" => {
+            return Some(Rc::new(RefCell::new(StringSlugify {
+                id: id,
+                input: None,
+                separator: String::from("-"),
+            })));
+        }
+        "split" => {
+            return Some(Rc::new(RefCell::new(Split {
+                id: id,
+                input: None,
+                delimiter: String::from(","),
+                regex: false,
+                max_splits: 0,
+                keep_empty: true,
+                splitter: patterns::compile(","),
+            })));
+        }
+        "split-each" => {
+            return Some(Rc::new(RefCell::new(SplitEach {
+                id: id,
+                input: None,
+                delimiter: String::from(","),
+                regex: false,
+                max_splits: 0,
+                keep_empty: true,
+                splitter: patterns::compile(","),
+                output: String::from("json"),
+                columns: String::new(),
+            })));
+        }
+        "join" => {
+            return Some(Rc::new(RefCell::new(Join {
+                id: id,
+                input: None,
+                separator: String::from(","),
+                prefix: String::new(),
+                suffix: String::new(),
+            })));
+        }
+        "sort" => {
+            return Some(Rc::new(RefCell::new(Sort {
+                id: id,
+                input: None,
+                order: String::from("ascending"),
+                compare: String::from("auto"),
+                key: String::new(),
+                unique: false,
+            })));
+        }
+        "take" => {
+            return Some(Rc::new(RefCell::new(TakeDrop {
+                id: id,
+                input: None,
+                operation: String::from("take"),
+                count: 10,
+                from: String::from("start"),
+            })));
+        }
+        "drop" => {
+            return Some(Rc::new(RefCell::new(TakeDrop {
+                id: id,
+                input: None,
+                operation: String::from("drop"),
+                count: 1,
+                from: String::from("start"),
+            })));
+        }
+        "slice" => {
+            return Some(Rc::new(RefCell::new(Slice {
+                id: id,
+                input: None,
+                start: None,
+                end: None,
+                step: 1,
+            })));
+        }
+        "chunk" => {
+            return Some(Rc::new(RefCell::new(Chunk {
+                id: id,
+                input: None,
+                size: 10,
+            })));
+        }
+        "window" => {
+            return Some(Rc::new(RefCell::new(Window {
+                id: id,
+                input: None,
+                size: 2,
+                step: 1,
+            })));
+        }
+        "reverse" => {
+            return Some(Rc::new(RefCell::new(Reverse {
+                id: id,
+                input: None,
+            })));
+        }
+        "union" | "intersection" | "difference" => {
+            return Some(Rc::new(RefCell::new(SetOperation {
+                id: id,
+                operation: type_.clone(),
+                inputs: vec![],
+                key: String::new(),
+            })));
+        }
+        "unique" => {
+            return Some(Rc::new(RefCell::new(Unique {
+                id: id,
+                input: None,
+                key: String::new(),
+            })));
+        }
+        "count-distinct" => {
+            return Some(Rc::new(RefCell::new(CountDistinct {
+                id: id,
+                input: None,
+                key: String::new(),
+            })));
+        }
+        "shuffle" => {
+            return Some(Rc::new(RefCell::new(Shuffle {
+                id: id,
+                input: None,
+                seed: 0,
+            })));
+        }
+        "sample" => {
+            return Some(Rc::new(RefCell::new(Sample {
+                id: id,
+                input: None,
+                count: 10,
+                seed: 0,
+            })));
+        }
+        "sample-fraction" => {
+            return Some(Rc::new(RefCell::new(SampleFraction {
+                id: id,
+                input: None,
+                fraction: 0.5,
+                seed: 0,
+            })));
+        }
+        "add" | "subtract" | "multiply" | "divide" | "modulo" | "power" | "min" | "max" => {
+            return Some(Rc::new(RefCell::new(Arithmetic {
+                id: id,
+                operation: type_.clone(),
+                left_input: None,
+                right_input: None,
+                value: String::new(),
+            })));
+        }
+        "aggregate" => {
+            return Some(Rc::new(RefCell::new(Aggregate {
+                id: id,
+                input: None,
+                function: String::from("summary"),
+                key: String::new(),
+                percentile: 50.0,
+                bins: 10,
+            })));
+        }
+        "group-by" => {
+            return Some(Rc::new(RefCell::new(GroupBy {
+                id: id,
+                input: None,
+                key: String::new(),
+                value: String::new(),
+                function: String::from("count"),
+                output: String::from("json"),
+                sort: String::from("none"),
+                order: String::from("ascending"),
+            })));
+        }
+        "join-records" => {
+            return Some(Rc::new(RefCell::new(JoinRecords {
+                id: id,
+                left_input: None,
+                right_input: None,
+                mode: String::from("inner"),
+                left_key: String::new(),
+                right_key: String::new(),
+                conflicts: String::from("suffix"),
+                suffix: String::from("_right"),
+                output: String::from("json"),
             })));
         }
         _ => return None,
@@ -56,6 +56,12 @@ impl Command for SaveCommand {
                         SpecAttribute::Int(name, value) => {
                             hash.insert(Yaml::String(name), Yaml::Integer(value));
                         }
+                        SpecAttribute::Float(name, value) => {
+                            hash.insert(Yaml::String(name), Yaml::Real(value.to_string()));
+                        }
+                        SpecAttribute::Bool(name, value) => {
+                            hash.insert(Yaml::String(name), Yaml::Boolean(value));
+                        }
                     }
                 }
                 Yaml::Hash(hash)
@@ -77,6 +83,10 @@ impl Command for SaveCommand {
                     Yaml::String(String::from("node")),
                     Yaml::Integer(connection.to),
                 );
+                to_hash.insert(
+                    Yaml::String(String::from("input")),
+                    Yaml::Integer(connection.input),
+                );
 
                 let mut hash = Hash::new();
                 hash.insert(Yaml::String(String::from("from")), Yaml::Hash(from_hash));
@@ -114,6 +114,7 @@ pub struct CreateConnectionCommand {
     id: conrod::widget::id::Id,
     from: i64,
     to: i64,
+    input: i64,
 }
 
 impl CreateConnectionCommand {
@@ -122,6 +123,7 @@ impl CreateConnectionCommand {
             id: id,
             from: from,
             to: to,
+            input: 1,
         }
     }
 
@@ -132,25 +134,36 @@ impl CreateConnectionCommand {
 
 impl Command for CreateConnectionCommand {
     fn execute(&mut self, params: &mut Params) {
+        // Connections go to the lowest input on the target that is not already connected so that
+        // nodes with several inputs can have each of them wired up in turn
+        self.input = 1;
+        while params
+            .connections
+            .values()
+            .any(|conn| conn.to == self.to && conn.input == self.input)
+        {
+            self.input += 1;
+        }
         self.redo(params)
     }
 
     fn redo(&mut self, params: &mut Params) {
-        build::connect(self.from, None, self.to, Some(1), &params.node_map);
+        build::connect(self.from, None, self.to, Some(self.input), &params.node_map);
 
         params.connections.insert(
-            (self.from, self.to),
+            (self.from, self.to, self.input),
             Connection {
                 id: self.id,
                 from: self.from,
                 to: self.to,
+                input: self.input,
             },
         );
     }
 
     fn undo(&mut self, params: &mut Params) {
-        build::disconnect(self.to, Some(1), &params.node_map);
-        params.connections.remove(&(self.from, self.to));
+        build::disconnect(self.to, Some(self.input), &params.node_map);
+        params.connections.remove(&(self.from, self.to, self.input));
     }
 }
 
@@ -178,26 +191,36 @@ impl DisconnectCommand {
 
 impl Command for DisconnectCommand {
     fn execute(&mut self, params: &mut Params) {
-        self.connection = params.connections.remove(&(self.from, self.to));
+        // When the nodes are connected more than once the lowest input is disconnected
+        let key = params
+            .connections
+            .keys()
+            .filter(|&&(from, to, _)| from == self.from && to == self.to)
+            .min()
+            .cloned();
+        self.connection = key.and_then(|key| params.connections.remove(&key));
         self.redo(params)
     }
 
     fn redo(&mut self, params: &mut Params) {
-        build::disconnect(self.to, Some(1), &params.node_map);
+        let input = self.connection.as_ref().map_or(1, |conn| conn.input);
+        build::disconnect(self.to, Some(input), &params.node_map);
     }
 
     fn undo(&mut self, params: &mut Params) {
-        build::connect(self.from, None, self.to, Some(1), &params.node_map);
+        let input = self.connection.as_ref().map_or(1, |conn| conn.input);
+        build::connect(self.from, None, self.to, Some(input), &params.node_map);
 
         if let Some(ref conn) = self.connection {
             // I can't figure out how to clone the old connection so I have to create a new one
             // with the same data to insert into the map
             params.connections.insert(
-                (self.from, self.to),
+                (self.from, self.to, conn.input),
                 Connection {
                     id: conn.id,
                     from: self.from,
                     to: self.to,
+                    input: conn.input,
                 },
             );
         }
@@ -24,6 +24,7 @@ pub struct Connection {
     pub id: conrod::widget::id::Id,
     pub from: i64,
     pub to: i64,
+    pub input: i64,
 }
 
 widget_ids! {
@@ -308,37 +309,91 @@ fn draw_parameters(
 ) {
     use conrod::{color, widget, Colorable, Positionable, Sizeable, Widget};
 
-    match param_ui {
-        &NodeUI::None => {
-            let id = ids.walk().next(ids, &mut ui.widget_id_generator());
-            widget::Text::new("Nothing")
-                .parent(parent_id)
-                .middle_of(parent_id)
-                .set(id, ui);
-        }
-        &NodeUI::StringField(ref data) => {
-            let mut bn = node.borrow_mut();
-            let nn = bn.deref_mut();
-            let id = ids.walk().next(ids, &mut ui.widget_id_generator());
+    let fields = match param_ui {
+        &NodeUI::None => vec![],
+        &NodeUI::List(ref fields) => fields.iter().collect(),
+        field => vec![field],
+    };
+
+    if fields.is_empty() {
+        ids.resize(1, &mut ui.widget_id_generator());
+        widget::Text::new("Nothing")
+            .parent(parent_id)
+            .middle_of(parent_id)
+            .set(ids[0], ui);
+        return;
+    }
+
+    // Each field is drawn as a label with its control underneath so we need two ids per field
+    ids.resize(fields.len() * 2, &mut ui.widget_id_generator());
+
+    let mut bn = node.borrow_mut();
+    let nn = bn.deref_mut();
+
+    for (index, field) in fields.iter().enumerate() {
+        let label_id = ids[index * 2];
+        let control_id = ids[index * 2 + 1];
+        let top = 10.0 + index as f64 * 60.0;
 
-            if let NodeUIData::StringData(value) = nn.get_value(&data.field) {
-                for event in widget::TextBox::new(value.as_str())
+        match *field {
+            &NodeUI::StringField(ref data) => {
+                widget::Text::new(data.label.as_str())
                     .parent(parent_id)
-                    .middle_of(parent_id)
+                    .top_left_with_margins_on(parent_id, top, 10.0)
                     .color(color::WHITE)
-                    .w(200.0)
-                    .h(30.0)
-                    .left_justify()
-                    .set(id, ui)
-                {
-                    match event {
-                        widget::text_box::Event::Update(string) => {
-                            nn.set_value(&data.field, NodeUIData::StringData(string));
+                    .font_size(14)
+                    .set(label_id, ui);
+
+                if let NodeUIData::StringData(value) = nn.get_value(&data.field) {
+                    for event in widget::TextBox::new(value.as_str())
+                        .parent(parent_id)
+                        .top_left_with_margins_on(parent_id, top + 20.0, 10.0)
+                        .color(color::WHITE)
+                        .w(200.0)
+                        .h(30.0)
+                        .left_justify()
+                        .set(control_id, ui)
+                    {
+                        match event {
+                            widget::text_box::Event::Update(string) => {
+                                nn.set_value(&data.field, NodeUIData::StringData(string));
+                            }
+                            _ => {}
                         }
-                        _ => {}
                     }
                 }
             }
+            &NodeUI::BoolField(ref data) => {
+                widget::Text::new(data.label.as_str())
+                    .parent(parent_id)
+                    .top_left_with_margins_on(parent_id, top, 10.0)
+                    .color(color::WHITE)
+                    .font_size(14)
+                    .set(label_id, ui);
+
+                if let NodeUIData::BoolData(value) = nn.get_value(&data.field) {
+                    for value in widget::Toggle::new(value)
+                        .parent(parent_id)
+                        .top_left_with_margins_on(parent_id, top + 20.0, 10.0)
+                        .w(30.0)
+                        .h(30.0)
+                        .set(control_id, ui)
+                    {
+                        nn.set_value(&data.field, NodeUIData::BoolData(value));
+                    }
+                }
+            }
+            &NodeUI::Error(ref message) => {
+                widget::Text::new(message.as_str())
+                    .parent(parent_id)
+                    .top_left_with_margins_on(parent_id, top, 10.0)
+                    .color(color::RED)
+                    .font_size(12)
+                    .w(200.0)
+                    .wrap_by_word()
+                    .set(label_id, ui);
+            }
+            _ => {}
         }
     }
 }
@@ -567,7 +622,7 @@ fn set_ui(
         .set(ids.scrollbar, ui);
 }
 
-fn find_input_node(id: i64, connections: &HashMap<(i64, i64), Connection>) -> Option<i64> {
+fn find_input_node(id: i64, connections: &HashMap<(i64, i64, i64), Connection>) -> Option<i64> {
     for (_key, connection) in connections {
         if connection.to == id {
             return Some(connection.from);
@@ -576,7 +631,7 @@ fn find_input_node(id: i64, connections: &HashMap<(i64, i64), Connection>) -> Op
     None
 }
 
-fn find_output_node(id: i64, connections: &HashMap<(i64, i64), Connection>) -> Option<i64> {
+fn find_output_node(id: i64, connections: &HashMap<(i64, i64, i64), Connection>) -> Option<i64> {
     for (_key, connection) in connections {
         if connection.from == id {
             return Some(connection.to);
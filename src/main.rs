@@ -1,5 +1,13 @@
+extern crate chrono;
 extern crate clap;
+extern crate csv;
+extern crate glob;
 extern crate json;
+extern crate regex;
+extern crate roxmltree;
+extern crate scraper;
+extern crate toml;
+extern crate walkdir;
 extern crate yaml_rust;
 
 #[macro_use]
@@ -37,7 +45,20 @@ pub enum FlowData {
     StringArray(Vec<String>),
     Int(i64),
     IntArray(Vec<i64>),
+    Float(f64),
+    FloatArray(Vec<f64>),
+    Bool(bool),
+    BoolArray(Vec<bool>),
+    DateTime(chrono::DateTime<chrono::FixedOffset>),
+    DateTimeArray(Vec<chrono::DateTime<chrono::FixedOffset>>),
     Json(json::JsonValue),
+    Table(Table),
+}
+
+#[derive(Debug, Clone)]
+pub struct Table {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<String>>,
 }
 
 pub struct StringFieldData {
@@ -45,20 +66,31 @@ pub struct StringFieldData {
     pub field: String,
 }
 
+pub struct BoolFieldData {
+    pub label: String,
+    pub field: String,
+}
+
 pub enum NodeUI {
     None,
     StringField(StringFieldData),
+    BoolField(BoolFieldData),
+    List(Vec<NodeUI>),
+    Error(String),
 }
 
 #[derive(Debug)]
 pub enum NodeUIData {
     None,
     StringData(String),
+    BoolData(bool),
 }
 
 pub enum SpecAttribute {
     String(String, String),
     Int(String, i64),
+    Float(String, f64),
+    Bool(String, bool),
 }
 
 pub struct Spec {
@@ -105,6 +137,62 @@ fn build(entry: &Yaml) -> Option<NodeRef> {
         Box::new(nodes::LinesBuilder {}),
         Box::new(nodes::SumBuilder {}),
         Box::new(nodes::ToIntBuilder {}),
+        Box::new(nodes::ParseDateBuilder {}),
+        Box::new(nodes::FormatDateBuilder {}),
+        Box::new(nodes::DateTimezoneBuilder {}),
+        Box::new(nodes::DateDiffBuilder {}),
+        Box::new(nodes::DateTruncateBuilder {}),
+        Box::new(nodes::CsvParseBuilder {}),
+        Box::new(nodes::CsvStringifyBuilder {}),
+        Box::new(nodes::FileInBuilder {}),
+        Box::new(nodes::FileOutBuilder {}),
+        Box::new(nodes::ListFilesBuilder {}),
+        Box::new(nodes::JsonQueryBuilder {}),
+        Box::new(nodes::JsonValuesBuilder {}),
+        Box::new(nodes::JsonPluckBuilder {}),
+        Box::new(nodes::JsonOmitBuilder {}),
+        Box::new(nodes::JsonMergeBuilder {}),
+        Box::new(nodes::JsonMergePatchBuilder {}),
+        Box::new(nodes::JsonPatchBuilder {}),
+        Box::new(nodes::JsonDiffBuilder {}),
+        Box::new(nodes::JsonFlattenBuilder {}),
+        Box::new(nodes::JsonUnflattenBuilder {}),
+        Box::new(nodes::NdjsonParseBuilder {}),
+        Box::new(nodes::NdjsonStringifyBuilder {}),
+        Box::new(nodes::YamlParseBuilder {}),
+        Box::new(nodes::YamlStringifyBuilder {}),
+        Box::new(nodes::TomlParseBuilder {}),
+        Box::new(nodes::TomlStringifyBuilder {}),
+        Box::new(nodes::XmlParseBuilder {}),
+        Box::new(nodes::HtmlSelectBuilder {}),
+        Box::new(nodes::JsonValidateBuilder {}),
+        Box::new(nodes::RegexFilterBuilder {}),
+        Box::new(nodes::RegexReplaceBuilder {}),
+        Box::new(nodes::RegexExtractBuilder {}),
+        Box::new(nodes::StringTrimBuilder {}),
+        Box::new(nodes::StringPrependBuilder {}),
+        Box::new(nodes::StringAppendBuilder {}),
+        Box::new(nodes::StringCaseBuilder {}),
+        Box::new(nodes::StringSlugifyBuilder {}),
+        Box::new(nodes::SplitBuilder {}),
+        Box::new(nodes::SplitEachBuilder {}),
+        Box::new(nodes::JoinBuilder {}),
+        Box::new(nodes::SortBuilder {}),
+        Box::new(nodes::TakeDropBuilder {}),
+        Box::new(nodes::SliceBuilder {}),
+        Box::new(nodes::ChunkBuilder {}),
+        Box::new(nodes::WindowBuilder {}),
+        Box::new(nodes::ReverseBuilder {}),
+        Box::new(nodes::SetOperationBuilder {}),
+        Box::new(nodes::UniqueBuilder {}),
+        Box::new(nodes::CountDistinctBuilder {}),
+        Box::new(nodes::ShuffleBuilder {}),
+        Box::new(nodes::SampleBuilder {}),
+        Box::new(nodes::SampleFractionBuilder {}),
+        Box::new(nodes::ArithmeticBuilder {}),
+        Box::new(nodes::AggregateBuilder {}),
+        Box::new(nodes::GroupByBuilder {}),
+        Box::new(nodes::JoinRecordsBuilder {}),
     ];
 
     match (entry["id"].as_i64(), entry["type"].as_str()) {
@@ -153,20 +241,23 @@ fn load_file(filename: String, mut generator: conrod::widget::id::Generator, par
 
     match yaml_connections {
         Some(ref connections) => for connection in connections.iter() {
+            // Connections without an explicit input index go to the first input
+            let input = connection["to"]["input"].as_i64().unwrap_or(1);
             match (
                 connection["from"]["node"].as_i64(),
                 connection["to"]["node"].as_i64(),
             ) {
                 (Some(from), Some(to)) => {
-                    build::connect(from, None, to, Some(1), &params.node_map);
+                    build::connect(from, None, to, Some(input), &params.node_map);
                     node_connections.push((from, to));
                     let gui_id = generator.next();
                     params.connections.insert(
-                        (from, to),
+                        (from, to, input),
                         Connection {
                             id: gui_id,
                             from: from,
                             to: to,
+                            input: input,
                         },
                     );
                 }
@@ -218,7 +309,7 @@ fn run(params: &mut Params) {
         let mut id = *node_id;
         while repeat {
             repeat = false;
-            for (&(from, to), _) in params.connections.iter() {
+            for (&(from, to, _), _) in params.connections.iter() {
                 if from == id {
                     id = to;
                     repeat = true;
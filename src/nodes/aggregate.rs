@@ -0,0 +1,156 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::statistics;
+
+pub struct Aggregate {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub function: String,
+    pub key: String,
+    pub percentile: f64,
+    pub bins: i64,
+}
+
+impl Node for Aggregate {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        if !statistics::FUNCTIONS.contains(&self.function.as_str()) {
+            return FlowData::Error(format!("Unknown function '{}'", self.function));
+        }
+
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                let numbers = match statistics::numbers(content, self.key.as_str()) {
+                    Ok(numbers) => numbers,
+                    Err(error) => return FlowData::Error(error),
+                };
+
+                return match statistics::aggregate(
+                    &numbers,
+                    self.function.as_str(),
+                    self.percentile,
+                    self.bins,
+                ) {
+                    Ok(content) => content,
+                    Err(error) => FlowData::Error(error),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::List(vec![
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Function (eg. sum, mean, percentile or summary)"),
+                field: String::from("function"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Key (json path or column)"),
+                field: String::from("key"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Percentile (0 to 100)"),
+                field: String::from("percentile"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Histogram bins"),
+                field: String::from("bins"),
+            }),
+        ])
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "function" => NodeUIData::StringData(self.function.clone()),
+            "key" => NodeUIData::StringData(self.key.clone()),
+            "percentile" => NodeUIData::StringData(self.percentile.to_string()),
+            "bins" => NodeUIData::StringData(self.bins.to_string()),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("function", NodeUIData::StringData(string)) => {
+                self.function = string;
+            }
+            ("key", NodeUIData::StringData(string)) => {
+                self.key = string;
+            }
+            ("percentile", NodeUIData::StringData(string)) => {
+                // An empty field is treated as zero so that it can be retyped
+                if string.trim().is_empty() {
+                    self.percentile = 0.0;
+                } else if let Ok(percentile) = string.trim().parse::<f64>() {
+                    self.percentile = percentile;
+                }
+            }
+            ("bins", NodeUIData::StringData(string)) => {
+                if string.trim().is_empty() {
+                    self.bins = 0;
+                } else if let Ok(bins) = string.trim().parse::<i64>() {
+                    self.bins = bins;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("aggregate"),
+            attributes: vec![
+                SpecAttribute::String(String::from("function"), self.function.clone()),
+                SpecAttribute::String(String::from("key"), self.key.clone()),
+                SpecAttribute::Float(String::from("percentile"), self.percentile),
+                SpecAttribute::Int(String::from("bins"), self.bins),
+            ],
+        }
+    }
+}
+
+pub struct AggregateBuilder {}
+
+impl NodeBuilder for AggregateBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "aggregate" {
+            // Whole numbers are read as integers by the yaml parser
+            let percentile = entry["percentile"]
+                .as_f64()
+                .or_else(|| entry["percentile"].as_i64().map(|percentile| percentile as f64));
+            return Some(Rc::new(RefCell::new(Aggregate {
+                id: id,
+                input: None,
+                function: String::from(entry["function"].as_str().unwrap_or("summary")),
+                key: String::from(entry["key"].as_str().unwrap_or("")),
+                percentile: percentile.unwrap_or(50.0),
+                bins: entry["bins"].as_i64().unwrap_or(10),
+            })));
+        }
+        None
+    }
+}
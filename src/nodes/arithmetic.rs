@@ -0,0 +1,266 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+pub const OPERATIONS: [&'static str; 8] =
+    ["add", "subtract", "multiply", "divide", "modulo", "power", "min", "max"];
+
+enum Numbers {
+    Ints(Vec<i64>),
+    Floats(Vec<f64>),
+}
+
+impl Numbers {
+    fn floats(self) -> Vec<f64> {
+        match self {
+            Numbers::Ints(values) => values.into_iter().map(|value| value as f64).collect(),
+            Numbers::Floats(values) => values,
+        }
+    }
+}
+
+// A single number is held as an array of one
+struct Operand {
+    numbers: Numbers,
+    array: bool,
+}
+
+fn operand(content: FlowData) -> Result<Operand, String> {
+    let (numbers, array) = match content {
+        FlowData::Int(value) => (Numbers::Ints(vec![value]), false),
+        FlowData::IntArray(values) => (Numbers::Ints(values), true),
+        FlowData::Float(value) => (Numbers::Floats(vec![value]), false),
+        FlowData::FloatArray(values) => (Numbers::Floats(values), true),
+        FlowData::Error(string) => return Err(string),
+        _ => return Err("Incorrect inputs".to_string()),
+    };
+    Ok(Operand {
+        numbers: numbers,
+        array: array,
+    })
+}
+
+// Reads the value attribute as an Int when it is a whole number & otherwise as a Float
+fn parse_value(text: &str) -> Result<FlowData, String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err("Insufficient inputs".to_string());
+    }
+    if let Ok(value) = text.parse::<i64>() {
+        return Ok(FlowData::Int(value));
+    }
+    match text.parse::<f64>() {
+        Ok(value) => Ok(FlowData::Float(value)),
+        Err(_) => Err(format!("'{}' is not a number", text)),
+    }
+}
+
+fn int_operation(operation: &str, a: i64, b: i64) -> Result<i64, String> {
+    let result = match operation {
+        "add" => a.checked_add(b),
+        "subtract" => a.checked_sub(b),
+        "multiply" => a.checked_mul(b),
+        "divide" | "modulo" if b == 0 => return Err("Division by zero".to_string()),
+        "divide" => a.checked_div(b),
+        "modulo" => a.checked_rem(b),
+        "power" if b < 0 => return Err("Int powers need an exponent of at least 0".to_string()),
+        "power" if b > u32::MAX as i64 => None,
+        "power" => a.checked_pow(b as u32),
+        "min" => Some(a.min(b)),
+        "max" => Some(a.max(b)),
+        other => return Err(format!("Unknown operation '{}'", other)),
+    };
+    result.ok_or_else(|| "Overflow".to_string())
+}
+
+fn float_operation(operation: &str, a: f64, b: f64) -> Result<f64, String> {
+    let result = match operation {
+        "add" => a + b,
+        "subtract" => a - b,
+        "multiply" => a * b,
+        "divide" | "modulo" if b == 0.0 => return Err("Division by zero".to_string()),
+        "divide" => a / b,
+        "modulo" => a % b,
+        "power" => a.powf(b),
+        "min" => a.min(b),
+        "max" => a.max(b),
+        other => return Err(format!("Unknown operation '{}'", other)),
+    };
+    if a.is_finite() && b.is_finite() && !result.is_finite() {
+        return Err("Result is not a finite number".to_string());
+    }
+    Ok(result)
+}
+
+// A side that is not an array holds a single number which is used against every entry of the other
+fn apply<T: Copy, F>(a: &[T], a_array: bool, b: &[T], b_array: bool, f: F) -> Result<Vec<T>, String>
+where
+    F: Fn(T, T) -> Result<T, String>,
+{
+    let length = match (a_array, b_array) {
+        (true, true) if a.len() != b.len() => {
+            return Err(format!("Arrays have different lengths ({} and {})", a.len(), b.len()))
+        }
+        (true, _) => a.len(),
+        (false, true) => b.len(),
+        (false, false) => 1,
+    };
+
+    (0..length)
+        .map(|index| {
+            let a = if a_array { a[index] } else { a[0] };
+            let b = if b_array { b[index] } else { b[0] };
+            f(a, b).map_err(|error| {
+                if a_array || b_array {
+                    format!("{} at index {}", error, index)
+                } else {
+                    error
+                }
+            })
+        })
+        .collect()
+}
+
+// Applies the operation to a pair of Ints, Floats or arrays of either. Arrays are combined entry by
+// entry & a single number is applied to every entry of an array. Any Float makes the result Float.
+pub fn calculate(operation: &str, left: FlowData, right: FlowData) -> FlowData {
+    let (left, right) = match (operand(left), operand(right)) {
+        (Ok(left), Ok(right)) => (left, right),
+        (Err(error), _) | (_, Err(error)) => return FlowData::Error(error),
+    };
+    let (left_array, right_array) = (left.array, right.array);
+    let array = left_array || right_array;
+
+    let result = match (left.numbers, right.numbers) {
+        (Numbers::Ints(a), Numbers::Ints(b)) => {
+            let f = |a, b| int_operation(operation, a, b);
+            apply(&a, left_array, &b, right_array, f).map(|values| match array {
+                true => FlowData::IntArray(values),
+                false => FlowData::Int(values[0]),
+            })
+        }
+        (a, b) => {
+            let f = |a, b| float_operation(operation, a, b);
+            apply(&a.floats(), left_array, &b.floats(), right_array, f).map(|values| match array {
+                true => FlowData::FloatArray(values),
+                false => FlowData::Float(values[0]),
+            })
+        }
+    };
+
+    match result {
+        Ok(content) => content,
+        Err(error) => FlowData::Error(error),
+    }
+}
+
+// Each operation takes input 1 on the left & input 2 on the right. The right hand side comes from
+// the value attribute when its input is not connected.
+pub struct Arithmetic {
+    pub id: i64,
+    pub operation: String,
+    pub left_input: Option<Rc<RefCell<Node>>>,
+    pub right_input: Option<Rc<RefCell<Node>>>,
+    pub value: String,
+}
+
+impl Node for Arithmetic {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        let left = match self.left_input {
+            Some(ref input) => input.borrow_mut().pull(),
+            None => return FlowData::Error("Insufficient inputs".to_string()),
+        };
+        let right = match self.right_input {
+            Some(ref input) => input.borrow_mut().pull(),
+            None => match parse_value(self.value.as_str()) {
+                Ok(right) => right,
+                Err(error) => return FlowData::Error(error),
+            },
+        };
+        calculate(self.operation.as_str(), left, right)
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, index: Option<i64>) -> () {
+        match index {
+            Some(1) => {
+                self.left_input = node;
+            }
+            Some(2) => {
+                self.right_input = node;
+            }
+            Some(_) => println!("Invalid input index for {}", self.operation),
+            None => println!("Missing input index for {}", self.operation),
+        }
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::StringField(StringFieldData {
+            label: String::from("Value (used without input 2)"),
+            field: String::from("value"),
+        })
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        if field == "value" {
+            return NodeUIData::StringData(self.value.clone());
+        }
+        NodeUIData::None
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("value", NodeUIData::StringData(string)) => {
+                self.value = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: self.operation.clone(),
+            attributes: vec![
+                SpecAttribute::String(String::from("value"), self.value.clone()),
+            ],
+        }
+    }
+}
+
+pub struct ArithmeticBuilder {}
+
+impl NodeBuilder for ArithmeticBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if OPERATIONS.contains(&name) {
+            // Numbers are kept as text so that the value can be an Int or a Float
+            let value = match entry["value"] {
+                Yaml::Integer(value) => value.to_string(),
+                Yaml::Real(ref value) | Yaml::String(ref value) => value.clone(),
+                _ => String::new(),
+            };
+            return Some(Rc::new(RefCell::new(Arithmetic {
+                id: id,
+                operation: String::from(name),
+                left_input: None,
+                right_input: None,
+                value: value,
+            })));
+        }
+        None
+    }
+}
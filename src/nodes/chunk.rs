@@ -0,0 +1,90 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::sequences;
+
+pub struct Chunk {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub size: i64,
+}
+
+impl Node for Chunk {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+                sequences::groups(content, self.size, self.size, true)
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::StringField(StringFieldData {
+            label: String::from("Size"),
+            field: String::from("size"),
+        })
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        if field == "size" {
+            return NodeUIData::StringData(self.size.to_string());
+        }
+        NodeUIData::None
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("size", NodeUIData::StringData(string)) => {
+                if let Ok(size) = string.trim().parse::<i64>() {
+                    self.size = size;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("chunk"),
+            attributes: vec![SpecAttribute::Int(String::from("size"), self.size)],
+        }
+    }
+}
+
+pub struct ChunkBuilder {}
+
+impl NodeBuilder for ChunkBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "chunk" {
+            return Some(Rc::new(RefCell::new(Chunk {
+                id: id,
+                input: None,
+                size: entry["size"].as_i64().unwrap_or(10),
+            })));
+        }
+        None
+    }
+}
@@ -0,0 +1,101 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::sets;
+
+pub struct CountDistinct {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub key: String,
+}
+
+impl Node for CountDistinct {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                return match content {
+                    FlowData::Error(string) => FlowData::Error(string),
+                    content => match sets::identities(&content, self.key.as_str()) {
+                        Ok(identities) => {
+                            let distinct: HashSet<String> = identities.into_iter().collect();
+                            FlowData::Int(distinct.len() as i64)
+                        }
+                        Err(error) => FlowData::Error(error),
+                    },
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::StringField(StringFieldData {
+            label: String::from("Key (json path or column)"),
+            field: String::from("key"),
+        })
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        if field == "key" {
+            return NodeUIData::StringData(self.key.clone());
+        }
+        NodeUIData::None
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("key", NodeUIData::StringData(string)) => {
+                self.key = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("count-distinct"),
+            attributes: vec![
+                SpecAttribute::String(String::from("key"), self.key.clone()),
+            ],
+        }
+    }
+}
+
+pub struct CountDistinctBuilder {}
+
+impl NodeBuilder for CountDistinctBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "count-distinct" {
+            return Some(Rc::new(RefCell::new(CountDistinct {
+                id: id,
+                input: None,
+                key: String::from(entry["key"].as_str().unwrap_or("")),
+            })));
+        }
+        None
+    }
+}
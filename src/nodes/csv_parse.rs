@@ -0,0 +1,196 @@
+extern crate json;
+
+use yaml_rust::Yaml;
+use csv::ReaderBuilder;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use BoolFieldData;
+use FlowData;
+use Table;
+
+// Allows the delimiter to be written out by name as tabs are hard to type into the node UI
+pub fn delimiter_byte(delimiter: &str) -> Result<u8, String> {
+    match delimiter {
+        "" | "comma" => Ok(b','),
+        "tab" | "\\t" | "\t" => Ok(b'\t'),
+        "semicolon" => Ok(b';'),
+        "pipe" => Ok(b'|'),
+        other if other.len() == 1 => Ok(other.as_bytes()[0]),
+        other => Err(format!("Invalid csv delimiter '{}'", other)),
+    }
+}
+
+pub struct CsvParse {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub delimiter: String,
+    pub headers: bool,
+    pub output: String,
+}
+
+impl CsvParse {
+    fn parse(&self, text: &str) -> Result<Table, String> {
+        let delimiter = delimiter_byte(self.delimiter.as_str())?;
+
+        // Headers are handled here rather than by the reader so that they can be generated
+        let mut reader = ReaderBuilder::new()
+            .delimiter(delimiter)
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(text.as_bytes());
+
+        let mut rows = vec![];
+        for record in reader.records() {
+            match record {
+                Ok(record) => rows.push(record.iter().map(String::from).collect::<Vec<_>>()),
+                Err(error) => return Err(format!("Failed to parse csv: {}", error)),
+            }
+        }
+
+        let columns = if self.headers && !rows.is_empty() {
+            rows.remove(0)
+        } else {
+            let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+            (1..width + 1).map(|index| index.to_string()).collect()
+        };
+
+        for row in rows.iter_mut() {
+            while row.len() < columns.len() {
+                row.push(String::new());
+            }
+        }
+
+        Ok(Table {
+            columns: columns,
+            rows: rows,
+        })
+    }
+
+    fn to_json(&self, table: Table) -> json::JsonValue {
+        let mut array = json::JsonValue::new_array();
+        for row in table.rows {
+            if self.headers {
+                let mut object = json::object::Object::new();
+                for (key, value) in table.columns.iter().zip(row.into_iter()) {
+                    object.insert(key, json::JsonValue::String(value));
+                }
+                let _ = array.push(json::JsonValue::Object(object));
+            } else {
+                let values = row.into_iter().map(json::JsonValue::String).collect();
+                let _ = array.push(json::JsonValue::Array(values));
+            }
+        }
+        array
+    }
+}
+
+impl Node for CsvParse {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                return match content {
+                    FlowData::String(text) => match self.parse(&text) {
+                        Ok(table) => match self.output.as_ref() {
+                            "json" => FlowData::Json(self.to_json(table)),
+                            _ => FlowData::Table(table),
+                        },
+                        Err(error) => FlowData::Error(error),
+                    },
+                    FlowData::Error(string) => FlowData::Error(string),
+                    _ => FlowData::Error("Unknown data".to_string()),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::List(vec![
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Delimiter"),
+                field: String::from("delimiter"),
+            }),
+            NodeUI::BoolField(BoolFieldData {
+                label: String::from("Header row"),
+                field: String::from("headers"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Output (table or json)"),
+                field: String::from("output"),
+            }),
+        ])
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "delimiter" => NodeUIData::StringData(self.delimiter.clone()),
+            "headers" => NodeUIData::BoolData(self.headers),
+            "output" => NodeUIData::StringData(self.output.clone()),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("delimiter", NodeUIData::StringData(string)) => {
+                self.delimiter = string;
+            }
+            ("headers", NodeUIData::BoolData(value)) => {
+                self.headers = value;
+            }
+            ("output", NodeUIData::StringData(string)) => {
+                self.output = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("csv-parse"),
+            attributes: vec![
+                SpecAttribute::String(String::from("delimiter"), self.delimiter.clone()),
+                SpecAttribute::Bool(String::from("headers"), self.headers),
+                SpecAttribute::String(String::from("output"), self.output.clone()),
+            ],
+        }
+    }
+}
+
+pub struct CsvParseBuilder {}
+
+impl NodeBuilder for CsvParseBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "csv-parse" {
+            return Some(Rc::new(RefCell::new(CsvParse {
+                id: id,
+                input: None,
+                delimiter: String::from(entry["delimiter"].as_str().unwrap_or(",")),
+                headers: entry["headers"].as_bool().unwrap_or(true),
+                output: String::from(entry["output"].as_str().unwrap_or("table")),
+            })));
+        }
+        None
+    }
+}
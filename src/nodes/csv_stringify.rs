@@ -0,0 +1,153 @@
+use yaml_rust::Yaml;
+use csv::WriterBuilder;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use BoolFieldData;
+use FlowData;
+use Table;
+
+use super::csv_parse::delimiter_byte;
+use super::records;
+
+pub struct CsvStringify {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub delimiter: String,
+    pub headers: bool,
+}
+
+// Writes the rows of a table as csv, with the column names first when headers is set
+pub fn table_csv(table: &Table, delimiter: u8, headers: bool) -> Result<String, String> {
+    let mut writer = WriterBuilder::new()
+        .delimiter(delimiter)
+        .flexible(true)
+        .from_writer(vec![]);
+
+    if headers {
+        if let Err(error) = writer.write_record(&table.columns) {
+            return Err(format!("Failed to write csv: {}", error));
+        }
+    }
+
+    for row in &table.rows {
+        if let Err(error) = writer.write_record(row) {
+            return Err(format!("Failed to write csv: {}", error));
+        }
+    }
+
+    match writer.into_inner() {
+        Ok(bytes) => String::from_utf8(bytes).map_err(|error| error.to_string()),
+        Err(error) => Err(format!("Failed to write csv: {}", error)),
+    }
+}
+
+impl CsvStringify {
+    fn stringify(&self, table: &Table) -> Result<String, String> {
+        let delimiter = delimiter_byte(self.delimiter.as_str())?;
+        table_csv(table, delimiter, self.headers)
+    }
+}
+
+impl Node for CsvStringify {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                let table = match content {
+                    FlowData::Table(table) => table,
+                    FlowData::Json(data) => match records::json_to_table(&data) {
+                        Ok(table) => table,
+                        Err(error) => return FlowData::Error(error),
+                    },
+                    FlowData::Error(string) => return FlowData::Error(string),
+                    _ => return FlowData::Error("Unknown data".to_string()),
+                };
+
+                return match self.stringify(&table) {
+                    Ok(string) => FlowData::String(string),
+                    Err(error) => FlowData::Error(error),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::List(vec![
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Delimiter"),
+                field: String::from("delimiter"),
+            }),
+            NodeUI::BoolField(BoolFieldData {
+                label: String::from("Header row"),
+                field: String::from("headers"),
+            }),
+        ])
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "delimiter" => NodeUIData::StringData(self.delimiter.clone()),
+            "headers" => NodeUIData::BoolData(self.headers),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("delimiter", NodeUIData::StringData(string)) => {
+                self.delimiter = string;
+            }
+            ("headers", NodeUIData::BoolData(value)) => {
+                self.headers = value;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("csv-stringify"),
+            attributes: vec![
+                SpecAttribute::String(String::from("delimiter"), self.delimiter.clone()),
+                SpecAttribute::Bool(String::from("headers"), self.headers),
+            ],
+        }
+    }
+}
+
+pub struct CsvStringifyBuilder {}
+
+impl NodeBuilder for CsvStringifyBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "csv-stringify" {
+            return Some(Rc::new(RefCell::new(CsvStringify {
+                id: id,
+                input: None,
+                delimiter: String::from(entry["delimiter"].as_str().unwrap_or(",")),
+                headers: entry["headers"].as_bool().unwrap_or(true),
+            })));
+        }
+        None
+    }
+}
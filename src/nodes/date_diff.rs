@@ -0,0 +1,142 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::dates;
+
+pub struct DateDiff {
+    pub id: i64,
+    pub start_input: Option<Rc<RefCell<Node>>>,
+    pub end_input: Option<Rc<RefCell<Node>>>,
+    pub unit: String,
+}
+
+impl DateDiff {
+    fn differences(&self, starts: &Vec<dates::Date>, ends: &Vec<dates::Date>) -> FlowData {
+        let mut output = vec![];
+        for (start, end) in starts.iter().zip(ends.iter()) {
+            match dates::difference(start, end, self.unit.as_str()) {
+                Ok(value) => output.push(value),
+                Err(error) => return FlowData::Error(error),
+            }
+        }
+        FlowData::IntArray(output)
+    }
+}
+
+impl Node for DateDiff {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match (self.start_input.clone(), self.end_input.clone()) {
+            (Some(ref mut start_input), Some(ref mut end_input)) => {
+                let start_content = start_input.borrow_mut().pull();
+                let end_content = end_input.borrow_mut().pull();
+
+                return match (start_content, end_content) {
+                    (FlowData::DateTime(start), FlowData::DateTime(end)) => {
+                        match dates::difference(&start, &end, self.unit.as_str()) {
+                            Ok(value) => FlowData::Int(value),
+                            Err(error) => FlowData::Error(error),
+                        }
+                    }
+                    (FlowData::DateTimeArray(starts), FlowData::DateTimeArray(ends)) => {
+                        if starts.len() != ends.len() {
+                            return FlowData::Error(format!(
+                                "Mismatched input lengths: {} & {}",
+                                starts.len(),
+                                ends.len()
+                            ));
+                        }
+                        self.differences(&starts, &ends)
+                    }
+                    (FlowData::DateTime(start), FlowData::DateTimeArray(ends)) => {
+                        self.differences(&vec![start; ends.len()], &ends)
+                    }
+                    (FlowData::DateTimeArray(starts), FlowData::DateTime(end)) => {
+                        self.differences(&starts, &vec![end; starts.len()])
+                    }
+                    (FlowData::Error(string), _) => FlowData::Error(string),
+                    (_, FlowData::Error(string)) => FlowData::Error(string),
+                    _ => FlowData::Error("Incorrect inputs".to_string()),
+                };
+            }
+            _ => FlowData::Error("Insufficient inputs".to_string()),
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, index: Option<i64>) -> () {
+        match index {
+            Some(1) => {
+                self.start_input = node;
+            }
+            Some(2) => {
+                self.end_input = node;
+            }
+            Some(_) => println!("Invalid input index for date-diff"),
+            None => println!("Missing input index for date-diff"),
+        }
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::StringField(StringFieldData {
+            label: String::from("Unit"),
+            field: String::from("unit"),
+        })
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        if field == "unit" {
+            return NodeUIData::StringData(self.unit.clone());
+        }
+        NodeUIData::None
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("unit", NodeUIData::StringData(string)) => {
+                self.unit = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("date-diff"),
+            attributes: vec![
+                SpecAttribute::String(String::from("unit"), self.unit.clone()),
+            ],
+        }
+    }
+}
+
+pub struct DateDiffBuilder {}
+
+impl NodeBuilder for DateDiffBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "date-diff" {
+            return Some(Rc::new(RefCell::new(DateDiff {
+                id: id,
+                start_input: None,
+                end_input: None,
+                unit: String::from(entry["unit"].as_str().unwrap_or("second")),
+            })));
+        }
+        None
+    }
+}
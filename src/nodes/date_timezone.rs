@@ -0,0 +1,106 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::dates;
+
+pub struct DateTimezone {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub offset: String,
+}
+
+impl Node for DateTimezone {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let offset = match dates::parse_offset(self.offset.as_str()) {
+                    Ok(offset) => offset,
+                    Err(error) => return FlowData::Error(error),
+                };
+
+                let content = input.borrow_mut().pull();
+
+                return match content {
+                    FlowData::DateTime(date) => FlowData::DateTime(date.with_timezone(&offset)),
+                    FlowData::DateTimeArray(values) => FlowData::DateTimeArray(
+                        values
+                            .iter()
+                            .map(|date| date.with_timezone(&offset))
+                            .collect(),
+                    ),
+                    FlowData::Error(string) => FlowData::Error(string),
+                    _ => FlowData::Error("Unknown data".to_string()),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::StringField(StringFieldData {
+            label: String::from("Offset"),
+            field: String::from("offset"),
+        })
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        if field == "offset" {
+            return NodeUIData::StringData(self.offset.clone());
+        }
+        NodeUIData::None
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("offset", NodeUIData::StringData(string)) => {
+                self.offset = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("date-timezone"),
+            attributes: vec![
+                SpecAttribute::String(String::from("offset"), self.offset.clone()),
+            ],
+        }
+    }
+}
+
+pub struct DateTimezoneBuilder {}
+
+impl NodeBuilder for DateTimezoneBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "date-timezone" {
+            return Some(Rc::new(RefCell::new(DateTimezone {
+                id: id,
+                input: None,
+                offset: String::from(entry["offset"].as_str().unwrap_or("+00:00")),
+            })));
+        }
+        None
+    }
+}
@@ -0,0 +1,108 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::dates;
+
+pub struct DateTruncate {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub unit: String,
+}
+
+impl Node for DateTruncate {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                return match content {
+                    FlowData::DateTime(date) => match dates::truncate(&date, self.unit.as_str()) {
+                        Ok(truncated) => FlowData::DateTime(truncated),
+                        Err(error) => FlowData::Error(error),
+                    },
+                    FlowData::DateTimeArray(values) => {
+                        let mut output = vec![];
+                        for date in &values {
+                            match dates::truncate(date, self.unit.as_str()) {
+                                Ok(truncated) => output.push(truncated),
+                                Err(error) => return FlowData::Error(error),
+                            }
+                        }
+                        return FlowData::DateTimeArray(output);
+                    }
+                    FlowData::Error(string) => FlowData::Error(string),
+                    _ => FlowData::Error("Unknown data".to_string()),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::StringField(StringFieldData {
+            label: String::from("Unit"),
+            field: String::from("unit"),
+        })
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        if field == "unit" {
+            return NodeUIData::StringData(self.unit.clone());
+        }
+        NodeUIData::None
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("unit", NodeUIData::StringData(string)) => {
+                self.unit = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("date-truncate"),
+            attributes: vec![
+                SpecAttribute::String(String::from("unit"), self.unit.clone()),
+            ],
+        }
+    }
+}
+
+pub struct DateTruncateBuilder {}
+
+impl NodeBuilder for DateTruncateBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "date-truncate" {
+            return Some(Rc::new(RefCell::new(DateTruncate {
+                id: id,
+                input: None,
+                unit: String::from(entry["unit"].as_str().unwrap_or("day")),
+            })));
+        }
+        None
+    }
+}
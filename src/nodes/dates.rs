@@ -0,0 +1,145 @@
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveDateTime, TimeZone,
+             Timelike};
+
+use std::fmt::Write;
+
+pub type Date = DateTime<FixedOffset>;
+
+// Format strings are either one of these presets or a strftime style pattern
+pub const RFC3339: &'static str = "rfc3339";
+pub const RFC2822: &'static str = "rfc2822";
+pub const EPOCH_SECONDS: &'static str = "epoch-seconds";
+pub const EPOCH_MILLIS: &'static str = "epoch-millis";
+
+fn utc() -> FixedOffset {
+    FixedOffset::east_opt(0).unwrap()
+}
+
+pub fn from_epoch(value: i64, format: &str) -> Option<Date> {
+    if format == EPOCH_MILLIS {
+        let nanos = (value.rem_euclid(1000) * 1_000_000) as u32;
+        utc().timestamp_opt(value.div_euclid(1000), nanos).single()
+    } else {
+        utc().timestamp_opt(value, 0).single()
+    }
+}
+
+pub fn parse(text: &str, format: &str) -> Result<Date, String> {
+    let text = text.trim();
+    let date = match format {
+        RFC3339 => DateTime::parse_from_rfc3339(text).ok(),
+        RFC2822 => DateTime::parse_from_rfc2822(text).ok(),
+        EPOCH_SECONDS | EPOCH_MILLIS => text.parse::<i64>()
+            .ok()
+            .and_then(|value| from_epoch(value, format)),
+        custom => {
+            // Formats without an offset or without a time are treated as UTC & midnight
+            DateTime::parse_from_str(text, custom)
+                .ok()
+                .or_else(|| {
+                    NaiveDateTime::parse_from_str(text, custom)
+                        .ok()
+                        .map(|naive| utc().from_utc_datetime(&naive))
+                })
+                .or_else(|| {
+                    NaiveDate::parse_from_str(text, custom)
+                        .ok()
+                        .and_then(|naive| naive.and_hms_opt(0, 0, 0))
+                        .map(|naive| utc().from_utc_datetime(&naive))
+                })
+        }
+    };
+
+    date.ok_or(format!(
+        "Unable to parse '{}' as a date with format '{}'",
+        text,
+        format
+    ))
+}
+
+pub fn format(date: &Date, format: &str) -> Result<String, String> {
+    match format {
+        RFC3339 => Ok(date.to_rfc3339()),
+        RFC2822 => Ok(date.to_rfc2822()),
+        EPOCH_SECONDS => Ok(date.timestamp().to_string()),
+        EPOCH_MILLIS => Ok(date.timestamp_millis().to_string()),
+        custom => {
+            // Writing rather than calling to_string as an invalid format would panic
+            let mut output = String::new();
+            match write!(output, "{}", date.format(custom)) {
+                Ok(_) => Ok(output),
+                Err(_) => Err(format!("Invalid date format '{}'", custom)),
+            }
+        }
+    }
+}
+
+// Accepts 'Z', 'UTC', '+HH', '+HHMM' & '+HH:MM' style offsets
+pub fn parse_offset(text: &str) -> Result<FixedOffset, String> {
+    let text = text.trim();
+    let error = format!("Invalid time-zone offset '{}'", text);
+
+    if text == "Z" || text == "UTC" {
+        return Ok(utc());
+    }
+
+    let sign = match text.chars().next() {
+        Some('+') => 1,
+        Some('-') => -1,
+        _ => return Err(error),
+    };
+
+    let digits: String = text[1..].chars().filter(|c| *c != ':').collect();
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(error);
+    }
+
+    let (hours, minutes) = match digits.len() {
+        2 => (digits.parse::<i32>().unwrap(), 0),
+        4 => (
+            digits[..2].parse::<i32>().unwrap(),
+            digits[2..].parse::<i32>().unwrap(),
+        ),
+        _ => return Err(error),
+    };
+
+    if minutes >= 60 {
+        return Err(error);
+    }
+
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).ok_or(error)
+}
+
+// Truncates in the date's own offset so that 'day' means the local calendar day
+pub fn truncate(date: &Date, unit: &str) -> Result<Date, String> {
+    let naive = date.naive_local();
+    let truncated = match unit {
+        "second" => naive.with_nanosecond(0),
+        "minute" => naive.date().and_hms_opt(naive.hour(), naive.minute(), 0),
+        "hour" => naive.date().and_hms_opt(naive.hour(), 0, 0),
+        "day" => naive.date().and_hms_opt(0, 0, 0),
+        "week" => {
+            let days = naive.weekday().num_days_from_monday() as i64;
+            (naive.date() - Duration::days(days)).and_hms_opt(0, 0, 0)
+        }
+        "month" => naive.date().with_day(1).and_then(|d| d.and_hms_opt(0, 0, 0)),
+        _ => return Err(format!("Unknown date unit '{}'", unit)),
+    };
+
+    truncated
+        .and_then(|naive| date.offset().from_local_datetime(&naive).single())
+        .ok_or(format!("Unable to truncate date to '{}'", unit))
+}
+
+pub fn difference(start: &Date, end: &Date, unit: &str) -> Result<i64, String> {
+    let duration = end.signed_duration_since(*start);
+    match unit {
+        "millisecond" => Ok(duration.num_milliseconds()),
+        "second" => Ok(duration.num_seconds()),
+        "minute" => Ok(duration.num_minutes()),
+        "hour" => Ok(duration.num_hours()),
+        "day" => Ok(duration.num_days()),
+        "week" => Ok(duration.num_weeks()),
+        _ => Err(format!("Unknown date unit '{}'", unit)),
+    }
+}
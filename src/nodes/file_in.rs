@@ -0,0 +1,189 @@
+extern crate json;
+
+use yaml_rust::Yaml;
+use glob::glob;
+
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::Path;
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+fn read_file(path: &str) -> Result<String, String> {
+    let mut content = String::new();
+    File::open(path)
+        .and_then(|mut file| file.read_to_string(&mut content))
+        .map(|_| content)
+        .map_err(|error| format!("Failed to read '{}': {}", path, error))
+}
+
+fn is_pattern(path: &str) -> bool {
+    path.contains(|c| c == '*' || c == '?' || c == '[')
+}
+
+pub struct FileIn {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub path: String,
+}
+
+impl FileIn {
+    // Each file becomes an object with 'path' & 'content' keys
+    fn read_paths(&self, paths: Vec<String>) -> Result<json::JsonValue, String> {
+        let mut records = json::JsonValue::new_array();
+        for path in paths {
+            let content = read_file(path.as_str())?;
+
+            let mut object = json::object::Object::new();
+            object.insert("path", json::JsonValue::String(path));
+            object.insert("content", json::JsonValue::String(content));
+            let _ = records.push(json::JsonValue::Object(object));
+        }
+
+        Ok(records)
+    }
+
+    fn read_pattern(&self) -> Result<json::JsonValue, String> {
+        let entries = match glob(self.path.as_str()) {
+            Ok(entries) => entries,
+            Err(error) => return Err(format!("Invalid pattern '{}': {}", self.path, error)),
+        };
+
+        let mut paths = vec![];
+        for entry in entries {
+            match entry {
+                Ok(ref path) if path.is_file() => {
+                    paths.push(path.to_string_lossy().into_owned());
+                }
+                Ok(_) => {}
+                Err(error) => return Err(error.to_string()),
+            }
+        }
+
+        self.read_paths(paths)
+    }
+
+    // Paths can come from a list of strings or from records with a 'path' key, as produced by
+    // the list-files node. Records with a 'type' other than a file, or a symbolic link to one, are
+    // skipped & every other path must be readable.
+    fn read_input(&self, content: FlowData) -> Result<json::JsonValue, String> {
+        let paths = match content {
+            FlowData::String(path) => return self.read_paths(vec![path]),
+            FlowData::StringArray(paths) => paths,
+            FlowData::Json(ref data) if data.is_array() => {
+                let mut paths = vec![];
+                for member in data.members() {
+                    let path = match member.as_str().or(member["path"].as_str()) {
+                        Some(path) => path,
+                        None => return Err(format!("No path found in {}", member.dump())),
+                    };
+                    match member["type"].as_str() {
+                        None | Some("file") => {}
+                        Some("symlink") if Path::new(path).is_file() => {}
+                        Some(_) => continue,
+                    }
+                    paths.push(path.to_string());
+                }
+                paths
+            }
+            FlowData::Error(string) => return Err(string),
+            _ => return Err("Unknown data".to_string()),
+        };
+
+        self.read_paths(paths)
+    }
+}
+
+impl Node for FileIn {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        if let Some(ref mut input) = self.input {
+            let content = input.borrow_mut().pull();
+            return match self.read_input(content) {
+                Ok(records) => FlowData::Json(records),
+                Err(error) => FlowData::Error(error),
+            };
+        }
+
+        if self.path.is_empty() {
+            return FlowData::Error("No path".to_string());
+        }
+
+        if is_pattern(self.path.as_str()) {
+            return match self.read_pattern() {
+                Ok(records) => FlowData::Json(records),
+                Err(error) => FlowData::Error(error),
+            };
+        }
+
+        match read_file(self.path.as_str()) {
+            Ok(content) => FlowData::String(content),
+            Err(error) => FlowData::Error(error),
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::StringField(StringFieldData {
+            label: String::from("Path"),
+            field: String::from("path"),
+        })
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        if field == "path" {
+            return NodeUIData::StringData(self.path.clone());
+        }
+        NodeUIData::None
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("path", NodeUIData::StringData(string)) => {
+                self.path = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("file-in"),
+            attributes: vec![
+                SpecAttribute::String(String::from("path"), self.path.clone()),
+            ],
+        }
+    }
+}
+
+pub struct FileInBuilder {}
+
+impl NodeBuilder for FileInBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "file-in" {
+            return Some(Rc::new(RefCell::new(FileIn {
+                id: id,
+                input: None,
+                path: String::from(entry["path"].as_str().unwrap_or("")),
+            })));
+        }
+        None
+    }
+}
@@ -0,0 +1,209 @@
+use yaml_rust::Yaml;
+
+use std::fs::{self, OpenOptions};
+use std::io::prelude::*;
+use std::path::Path;
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use BoolFieldData;
+use FlowData;
+
+use super::csv_stringify;
+
+fn lines<T: ToString>(values: &[T]) -> String {
+    let mut text = String::new();
+    for value in values {
+        text.push_str(&value.to_string());
+        text.push('\n');
+    }
+    text
+}
+
+// Arrays are written a line per entry, Json as compact text & Tables as csv with a header row
+fn file_text(content: FlowData) -> Result<String, String> {
+    match content {
+        FlowData::String(text) => Ok(text),
+        FlowData::StringArray(values) => Ok(lines(&values)),
+        FlowData::Int(value) => Ok(value.to_string()),
+        FlowData::IntArray(values) => Ok(lines(&values)),
+        FlowData::Float(value) => Ok(value.to_string()),
+        FlowData::FloatArray(values) => Ok(lines(&values)),
+        FlowData::Bool(value) => Ok(value.to_string()),
+        FlowData::BoolArray(values) => Ok(lines(&values)),
+        FlowData::DateTime(value) => Ok(value.to_rfc3339()),
+        FlowData::DateTimeArray(values) => {
+            let values: Vec<String> = values.iter().map(|value| value.to_rfc3339()).collect();
+            Ok(lines(&values))
+        }
+        FlowData::Json(data) => Ok(data.dump()),
+        FlowData::Table(table) => csv_stringify::table_csv(&table, b',', true),
+        FlowData::Error(string) => Err(string),
+        FlowData::None => Err("Nothing to write".to_string()),
+    }
+}
+
+pub struct FileOut {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub path: String,
+    pub mode: String,
+    pub create_dirs: bool,
+}
+
+impl FileOut {
+    fn write(&self, content: &str) -> Result<(), String> {
+        let path = Path::new(self.path.as_str());
+
+        if self.create_dirs {
+            if let Some(parent) = path.parent() {
+                if !parent.as_os_str().is_empty() {
+                    fs::create_dir_all(parent).map_err(|error| {
+                        format!("Failed to create '{}': {}", parent.display(), error)
+                    })?;
+                }
+            }
+        }
+
+        let result = match self.mode.as_ref() {
+            "append" => OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .and_then(|mut file| file.write_all(content.as_bytes())),
+            "overwrite" => {
+                // Write alongside the target & then rename so that readers never see a partial file
+                let file_name = match path.file_name() {
+                    Some(name) => name.to_string_lossy().into_owned(),
+                    None => return Err(format!("Invalid path '{}'", self.path)),
+                };
+                let temp_path = path.with_file_name(format!(".{}.mango-tmp", file_name));
+                let result = fs::File::create(&temp_path)
+                    .and_then(|mut file| {
+                        file.write_all(content.as_bytes())?;
+                        file.sync_all()
+                    })
+                    .and_then(|_| fs::rename(&temp_path, path));
+                if result.is_err() {
+                    let _ = fs::remove_file(&temp_path);
+                }
+                result
+            }
+            other => return Err(format!("Unknown write mode '{}'", other)),
+        };
+
+        result.map_err(|error| format!("Failed to write '{}': {}", self.path, error))
+    }
+}
+
+impl Node for FileOut {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                // Errors are printed as well as returned as nothing reads the result of an end node
+                let written = if self.path.is_empty() {
+                    Err("No path".to_string())
+                } else {
+                    file_text(content).and_then(|text| self.write(text.as_str()))
+                };
+
+                return match written {
+                    Ok(_) => FlowData::None,
+                    Err(error) => {
+                        println!("{}", error);
+                        FlowData::Error(error)
+                    }
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::List(vec![
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Path"),
+                field: String::from("path"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Mode (overwrite or append)"),
+                field: String::from("mode"),
+            }),
+            NodeUI::BoolField(BoolFieldData {
+                label: String::from("Create directories"),
+                field: String::from("create-dirs"),
+            }),
+        ])
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "path" => NodeUIData::StringData(self.path.clone()),
+            "mode" => NodeUIData::StringData(self.mode.clone()),
+            "create-dirs" => NodeUIData::BoolData(self.create_dirs),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("path", NodeUIData::StringData(string)) => {
+                self.path = string;
+            }
+            ("mode", NodeUIData::StringData(string)) => {
+                self.mode = string;
+            }
+            ("create-dirs", NodeUIData::BoolData(value)) => {
+                self.create_dirs = value;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("file-out"),
+            attributes: vec![
+                SpecAttribute::String(String::from("path"), self.path.clone()),
+                SpecAttribute::String(String::from("mode"), self.mode.clone()),
+                SpecAttribute::Bool(String::from("create-dirs"), self.create_dirs),
+            ],
+        }
+    }
+}
+
+pub struct FileOutBuilder {}
+
+impl NodeBuilder for FileOutBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "file-out" {
+            return Some(Rc::new(RefCell::new(FileOut {
+                id: id,
+                input: None,
+                path: String::from(entry["path"].as_str().unwrap_or("")),
+                mode: String::from(entry["mode"].as_str().unwrap_or("overwrite")),
+                create_dirs: entry["create-dirs"].as_bool().unwrap_or(true),
+            })));
+        }
+        None
+    }
+}
@@ -0,0 +1,108 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::dates;
+
+pub struct FormatDate {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub format: String,
+}
+
+impl Node for FormatDate {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                return match content {
+                    FlowData::DateTime(date) => match dates::format(&date, self.format.as_str()) {
+                        Ok(string) => FlowData::String(string),
+                        Err(error) => FlowData::Error(error),
+                    },
+                    FlowData::DateTimeArray(values) => {
+                        let mut output = vec![];
+                        for date in &values {
+                            match dates::format(date, self.format.as_str()) {
+                                Ok(string) => output.push(string),
+                                Err(error) => return FlowData::Error(error),
+                            }
+                        }
+                        return FlowData::StringArray(output);
+                    }
+                    FlowData::Error(string) => FlowData::Error(string),
+                    _ => FlowData::Error("Unknown data".to_string()),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::StringField(StringFieldData {
+            label: String::from("Format"),
+            field: String::from("format"),
+        })
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        if field == "format" {
+            return NodeUIData::StringData(self.format.clone());
+        }
+        NodeUIData::None
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("format", NodeUIData::StringData(string)) => {
+                self.format = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("format-date"),
+            attributes: vec![
+                SpecAttribute::String(String::from("format"), self.format.clone()),
+            ],
+        }
+    }
+}
+
+pub struct FormatDateBuilder {}
+
+impl NodeBuilder for FormatDateBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "format-date" {
+            return Some(Rc::new(RefCell::new(FormatDate {
+                id: id,
+                input: None,
+                format: String::from(entry["format"].as_str().unwrap_or(dates::RFC3339)),
+            })));
+        }
+        None
+    }
+}
@@ -0,0 +1,330 @@
+extern crate json;
+
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+use Table;
+
+use super::json_path;
+use super::ordering::{self, Key};
+use super::records;
+use super::statistics;
+
+const FUNCTIONS: [&'static str; 8] =
+    ["count", "sum", "mean", "median", "min", "max", "stddev", "collect"];
+
+fn column_index(table: &Table, name: &str) -> Result<usize, String> {
+    match table.columns.iter().position(|column| column == name) {
+        Some(column) => Ok(column),
+        None => Err(format!("No column named '{}'", name)),
+    }
+}
+
+// Numbers are ordered by value, with whole numbers kept exact, & everything else as text
+fn sort_key(value: &json::JsonValue) -> Key {
+    if !value.is_number() {
+        return Key::text(records::cell_text(value));
+    }
+    match (value.as_i64(), value.as_f64()) {
+        (Some(int), _) => Key::int(value.dump(), int),
+        (None, Some(number)) => Key::number(value.dump(), number),
+        (None, None) => Key::text(value.dump()),
+    }
+}
+
+pub struct GroupBy {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub key: String,
+    pub value: String,
+    pub function: String,
+    pub output: String,
+    pub sort: String,
+    pub order: String,
+}
+
+impl GroupBy {
+    // Pairs each record with its group & the value to aggregate, which is the whole record when
+    // there is no value path or column
+    fn records(&self, content: FlowData) -> Result<Vec<(String, json::JsonValue)>, String> {
+        match content {
+            FlowData::Json(json::JsonValue::Array(members)) => {
+                let key = json_path::parse(self.key.as_str())?;
+                let value = if self.value.is_empty() {
+                    None
+                } else {
+                    Some(json_path::parse(self.value.as_str())?)
+                };
+                Ok(members
+                    .iter()
+                    .map(|member| {
+                        let group = match json_path::query(member, &key).first() {
+                            Some(matched) => records::cell_text(matched),
+                            None => String::new(),
+                        };
+                        let value = match value {
+                            Some(ref value) => match json_path::query(member, value).first() {
+                                Some(matched) => (*matched).clone(),
+                                None => json::JsonValue::Null,
+                            },
+                            None => member.clone(),
+                        };
+                        (group, value)
+                    })
+                    .collect())
+            }
+            FlowData::Table(table) => {
+                let key = if self.key.is_empty() {
+                    0
+                } else {
+                    column_index(&table, self.key.as_str())?
+                };
+                let value = if self.value.is_empty() {
+                    None
+                } else {
+                    Some(column_index(&table, self.value.as_str())?)
+                };
+                let groups: Vec<String> = table
+                    .rows
+                    .iter()
+                    .map(|row| row.get(key).cloned().unwrap_or(String::new()))
+                    .collect();
+                let values: Vec<json::JsonValue> = match value {
+                    Some(value) => table
+                        .rows
+                        .iter()
+                        .map(|row| match row.get(value) {
+                            Some(cell) => records::infer_value(cell),
+                            None => json::JsonValue::Null,
+                        })
+                        .collect(),
+                    None => records::table_objects(table),
+                };
+                Ok(groups.into_iter().zip(values.into_iter()).collect())
+            }
+            FlowData::Error(string) => Err(string),
+            _ => Err("Unknown data".to_string()),
+        }
+    }
+
+    // Groups without any numbers give null rather than failing the whole node
+    fn aggregate(&self, values: Vec<json::JsonValue>) -> Result<json::JsonValue, String> {
+        match self.function.as_ref() {
+            "count" => return Ok(json::JsonValue::from(values.len())),
+            "collect" => return Ok(json::JsonValue::Array(values)),
+            _ => {}
+        }
+
+        let numbers = statistics::from_json(&values.iter().collect::<Vec<_>>());
+        if numbers.is_empty() && self.function != "sum" {
+            return Ok(json::JsonValue::Null);
+        }
+        statistics::aggregate(&numbers, self.function.as_str(), 50.0, 1).map(statistics::to_json)
+    }
+
+    fn group(&self, content: FlowData) -> Result<FlowData, String> {
+        let mut groups: Vec<(String, Vec<json::JsonValue>)> = vec![];
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        for (group, value) in self.records(content)? {
+            let position = *positions.entry(group.clone()).or_insert(groups.len());
+            if position == groups.len() {
+                groups.push((group, vec![]));
+            }
+            groups[position].1.push(value);
+        }
+
+        let mut results = vec![];
+        for (group, values) in groups {
+            let result = self.aggregate(values)?;
+            results.push((group, result));
+        }
+
+        let descending = self.order == "descending";
+        let keys: Option<Vec<Key>> = match self.sort.as_ref() {
+            "none" => None,
+            "group" => Some(
+                results
+                    .iter()
+                    .map(|&(ref group, _)| sort_key(&records::infer_value(group)))
+                    .collect(),
+            ),
+            "value" => Some(results.iter().map(|&(_, ref result)| sort_key(result)).collect()),
+            other => return Err(format!("Unknown sort '{}'", other)),
+        };
+        if let Some(keys) = keys {
+            let indices = ordering::sorted_indices(&keys, "auto", descending, false);
+            let mut slots: Vec<Option<(String, json::JsonValue)>> =
+                results.into_iter().map(Some).collect();
+            results = indices
+                .into_iter()
+                .filter_map(|index| slots[index].take())
+                .collect();
+        }
+
+        match self.output.as_ref() {
+            "json" => {
+                let mut object = json::object::Object::new();
+                for (group, result) in results {
+                    object.insert(group.as_str(), result);
+                }
+                Ok(FlowData::Json(json::JsonValue::Object(object)))
+            }
+            "table" => Ok(FlowData::Table(Table {
+                columns: vec![String::from("group"), self.function.clone()],
+                rows: results
+                    .into_iter()
+                    .map(|(group, result)| vec![group, records::cell_text(&result)])
+                    .collect(),
+            })),
+            other => Err(format!("Unknown output '{}'", other)),
+        }
+    }
+}
+
+impl Node for GroupBy {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        if !FUNCTIONS.contains(&self.function.as_str()) {
+            return FlowData::Error(format!("Unknown function '{}'", self.function));
+        }
+        // Whole records are not numbers so the numeric functions need to know where to look
+        if self.value.is_empty() && self.function != "count" && self.function != "collect" {
+            return FlowData::Error(format!("No value path or column for '{}'", self.function));
+        }
+        match self.order.as_ref() {
+            "ascending" | "descending" => {}
+            other => return FlowData::Error(format!("Unknown order '{}'", other)),
+        }
+
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                return match self.group(content) {
+                    Ok(content) => content,
+                    Err(error) => FlowData::Error(error),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::List(vec![
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Key (json path or column)"),
+                field: String::from("key"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Value (json path or column)"),
+                field: String::from("value"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Function (eg. count, sum, max or collect)"),
+                field: String::from("function"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Output (json or table)"),
+                field: String::from("output"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Sort (none, group or value)"),
+                field: String::from("sort"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Order (ascending or descending)"),
+                field: String::from("order"),
+            }),
+        ])
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "key" => NodeUIData::StringData(self.key.clone()),
+            "value" => NodeUIData::StringData(self.value.clone()),
+            "function" => NodeUIData::StringData(self.function.clone()),
+            "output" => NodeUIData::StringData(self.output.clone()),
+            "sort" => NodeUIData::StringData(self.sort.clone()),
+            "order" => NodeUIData::StringData(self.order.clone()),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("key", NodeUIData::StringData(string)) => {
+                self.key = string;
+            }
+            ("value", NodeUIData::StringData(string)) => {
+                self.value = string;
+            }
+            ("function", NodeUIData::StringData(string)) => {
+                self.function = string;
+            }
+            ("output", NodeUIData::StringData(string)) => {
+                self.output = string;
+            }
+            ("sort", NodeUIData::StringData(string)) => {
+                self.sort = string;
+            }
+            ("order", NodeUIData::StringData(string)) => {
+                self.order = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("group-by"),
+            attributes: vec![
+                SpecAttribute::String(String::from("key"), self.key.clone()),
+                SpecAttribute::String(String::from("value"), self.value.clone()),
+                SpecAttribute::String(String::from("function"), self.function.clone()),
+                SpecAttribute::String(String::from("output"), self.output.clone()),
+                SpecAttribute::String(String::from("sort"), self.sort.clone()),
+                SpecAttribute::String(String::from("order"), self.order.clone()),
+            ],
+        }
+    }
+}
+
+pub struct GroupByBuilder {}
+
+impl NodeBuilder for GroupByBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "group-by" {
+            return Some(Rc::new(RefCell::new(GroupBy {
+                id: id,
+                input: None,
+                key: String::from(entry["key"].as_str().unwrap_or("")),
+                value: String::from(entry["value"].as_str().unwrap_or("")),
+                function: String::from(entry["function"].as_str().unwrap_or("count")),
+                output: String::from(entry["output"].as_str().unwrap_or("json")),
+                sort: String::from(entry["sort"].as_str().unwrap_or("none")),
+                order: String::from(entry["order"].as_str().unwrap_or("ascending")),
+            })));
+        }
+        None
+    }
+}
@@ -0,0 +1,139 @@
+use yaml_rust::Yaml;
+use scraper::{Html, Selector};
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+pub struct HtmlSelect {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub selector: String,
+    pub attribute: String,
+}
+
+impl HtmlSelect {
+    // Matches are returned as their text content or, if an attribute is named, that attribute's
+    // value with elements that lack it being skipped
+    fn select(&self, selector: &Selector, text: &str, output: &mut Vec<String>) {
+        let document = Html::parse_document(text);
+        for element in document.select(selector) {
+            if self.attribute.is_empty() {
+                output.push(element.text().collect::<String>().trim().to_string());
+            } else if let Some(value) = element.value().attr(self.attribute.as_str()) {
+                output.push(value.to_string());
+            }
+        }
+    }
+}
+
+impl Node for HtmlSelect {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        let selector = match Selector::parse(self.selector.as_str()) {
+            Ok(selector) => selector,
+            Err(error) => {
+                return FlowData::Error(format!("Invalid selector '{}': {}", self.selector, error))
+            }
+        };
+
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                let mut output = vec![];
+                return match content {
+                    FlowData::String(text) => {
+                        self.select(&selector, &text, &mut output);
+                        FlowData::StringArray(output)
+                    }
+                    FlowData::StringArray(texts) => {
+                        for text in texts {
+                            self.select(&selector, &text, &mut output);
+                        }
+                        FlowData::StringArray(output)
+                    }
+                    FlowData::Error(string) => FlowData::Error(string),
+                    _ => FlowData::Error("Unknown data".to_string()),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::List(vec![
+            NodeUI::StringField(StringFieldData {
+                label: String::from("CSS selector"),
+                field: String::from("selector"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Attribute (empty for text)"),
+                field: String::from("attribute"),
+            }),
+        ])
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "selector" => NodeUIData::StringData(self.selector.clone()),
+            "attribute" => NodeUIData::StringData(self.attribute.clone()),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("selector", NodeUIData::StringData(string)) => {
+                self.selector = string;
+            }
+            ("attribute", NodeUIData::StringData(string)) => {
+                self.attribute = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("html-select"),
+            attributes: vec![
+                SpecAttribute::String(String::from("selector"), self.selector.clone()),
+                SpecAttribute::String(String::from("attribute"), self.attribute.clone()),
+            ],
+        }
+    }
+}
+
+pub struct HtmlSelectBuilder {}
+
+impl NodeBuilder for HtmlSelectBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "html-select" {
+            return Some(Rc::new(RefCell::new(HtmlSelect {
+                id: id,
+                input: None,
+                selector: String::from(entry["selector"].as_str().unwrap_or("")),
+                attribute: String::from(entry["attribute"].as_str().unwrap_or("")),
+            })));
+        }
+        None
+    }
+}
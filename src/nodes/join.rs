@@ -0,0 +1,124 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::strings;
+
+pub struct Join {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub separator: String,
+    pub prefix: String,
+    pub suffix: String,
+}
+
+impl Node for Join {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                return match content {
+                    FlowData::StringArray(values) => FlowData::String(format!(
+                        "{}{}{}",
+                        strings::unescape(&self.prefix),
+                        values.join(&strings::unescape(&self.separator)),
+                        strings::unescape(&self.suffix)
+                    )),
+                    FlowData::Error(string) => FlowData::Error(string),
+                    _ => FlowData::Error("Unknown data".to_string()),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::List(vec![
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Separator"),
+                field: String::from("separator"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Prefix"),
+                field: String::from("prefix"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Suffix"),
+                field: String::from("suffix"),
+            }),
+        ])
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "separator" => NodeUIData::StringData(self.separator.clone()),
+            "prefix" => NodeUIData::StringData(self.prefix.clone()),
+            "suffix" => NodeUIData::StringData(self.suffix.clone()),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("separator", NodeUIData::StringData(string)) => {
+                self.separator = string;
+            }
+            ("prefix", NodeUIData::StringData(string)) => {
+                self.prefix = string;
+            }
+            ("suffix", NodeUIData::StringData(string)) => {
+                self.suffix = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("join"),
+            attributes: vec![
+                SpecAttribute::String(String::from("separator"), self.separator.clone()),
+                SpecAttribute::String(String::from("prefix"), self.prefix.clone()),
+                SpecAttribute::String(String::from("suffix"), self.suffix.clone()),
+            ],
+        }
+    }
+}
+
+pub struct JoinBuilder {}
+
+impl NodeBuilder for JoinBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "join" {
+            return Some(Rc::new(RefCell::new(Join {
+                id: id,
+                input: None,
+                separator: String::from(entry["separator"].as_str().unwrap_or(",")),
+                prefix: String::from(entry["prefix"].as_str().unwrap_or("")),
+                suffix: String::from(entry["suffix"].as_str().unwrap_or("")),
+            })));
+        }
+        None
+    }
+}
@@ -0,0 +1,306 @@
+extern crate json;
+
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::json_path;
+use super::records;
+
+// Json arrays & Table rows are both joined as objects
+fn objects(content: FlowData) -> Result<Vec<json::JsonValue>, String> {
+    let values = match content {
+        FlowData::Json(json::JsonValue::Array(values)) => values,
+        FlowData::Table(table) => records::table_objects(table),
+        FlowData::Error(string) => return Err(string),
+        _ => return Err("Incorrect inputs".to_string()),
+    };
+    if values.iter().any(|value| !value.is_object()) {
+        return Err("Expected objects to join".to_string());
+    }
+    Ok(values)
+}
+
+// Keys are compared as text so that numbers from Json match the same numbers read from a Table.
+// Records without a key never match.
+fn keys(values: &[json::JsonValue], key: &str) -> Result<Vec<Option<String>>, String> {
+    let segments = json_path::parse(key)?;
+    Ok(values
+        .iter()
+        .map(|value| match json_path::query(value, &segments).first() {
+            Some(matched) if !matched.is_null() => Some(records::cell_text(matched)),
+            _ => None,
+        })
+        .collect())
+}
+
+pub struct JoinRecords {
+    pub id: i64,
+    pub left_input: Option<Rc<RefCell<Node>>>,
+    pub right_input: Option<Rc<RefCell<Node>>>,
+    pub mode: String,
+    pub left_key: String,
+    pub right_key: String,
+    pub conflicts: String,
+    pub suffix: String,
+    pub output: String,
+}
+
+impl JoinRecords {
+    // Fields only found on the right are added as they are. A field on both sides is only a conflict
+    // when its values differ as text, as with the keys.
+    fn merge(
+        &self,
+        left: &json::JsonValue,
+        right: &json::JsonValue,
+    ) -> Result<json::JsonValue, String> {
+        let mut merged = left.clone();
+        for (field, value) in right.entries() {
+            if !merged.has_key(field) {
+                merged[field] = value.clone();
+                continue;
+            }
+            if records::cell_text(&merged[field]) == records::cell_text(value) {
+                continue;
+            }
+            match self.conflicts.as_ref() {
+                "suffix" => {
+                    // The suffix is repeated until the name is free so no field is overwritten
+                    if self.suffix.is_empty() {
+                        return Err("A suffix is needed to rename conflicts".to_string());
+                    }
+                    let mut renamed = format!("{}{}", field, self.suffix);
+                    while merged.has_key(renamed.as_str()) {
+                        renamed.push_str(self.suffix.as_str());
+                    }
+                    merged[renamed.as_str()] = value.clone();
+                }
+                "left" => {}
+                "right" => merged[field] = value.clone(),
+                "error" => return Err(format!("Conflicting values for field '{}'", field)),
+                other => return Err(format!("Unknown conflict policy '{}'", other)),
+            }
+        }
+        Ok(merged)
+    }
+
+    // A hash join which indexes the right side by key & then looks up each left record in turn.
+    // Matches follow the order of the left side with unmatched right records at the end.
+    fn join(
+        &self,
+        left: Vec<json::JsonValue>,
+        right: Vec<json::JsonValue>,
+    ) -> Result<Vec<json::JsonValue>, String> {
+        let (keep_left, keep_right) = match self.mode.as_ref() {
+            "inner" => (false, false),
+            "left" => (true, false),
+            "right" => (false, true),
+            "full" => (true, true),
+            other => return Err(format!("Unknown join mode '{}'", other)),
+        };
+
+        let right_key = if self.right_key.is_empty() {
+            self.left_key.as_str()
+        } else {
+            self.right_key.as_str()
+        };
+        let left_keys = keys(&left, self.left_key.as_str())?;
+        let right_keys = keys(&right, right_key)?;
+
+        let mut index: HashMap<&str, Vec<usize>> = HashMap::new();
+        for (position, key) in right_keys.iter().enumerate() {
+            if let Some(ref key) = *key {
+                index.entry(key.as_str()).or_insert(vec![]).push(position);
+            }
+        }
+
+        let mut matched = vec![false; right.len()];
+        let mut output = vec![];
+        for (record, key) in left.iter().zip(left_keys.iter()) {
+            let positions = key.as_ref().and_then(|key| index.get(key.as_str()));
+            match positions {
+                Some(positions) => {
+                    for &position in positions {
+                        matched[position] = true;
+                        output.push(self.merge(record, &right[position])?);
+                    }
+                }
+                None => {
+                    if keep_left {
+                        output.push(record.clone());
+                    }
+                }
+            }
+        }
+
+        if keep_right {
+            for (position, record) in right.into_iter().enumerate() {
+                if !matched[position] {
+                    output.push(record);
+                }
+            }
+        }
+        Ok(output)
+    }
+}
+
+impl Node for JoinRecords {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match (&self.left_input, &self.right_input) {
+            (&Some(ref left_input), &Some(ref right_input)) => {
+                let left = objects(left_input.borrow_mut().pull());
+                let right = objects(right_input.borrow_mut().pull());
+
+                let joined = match (left, right) {
+                    (Ok(left), Ok(right)) => self.join(left, right),
+                    (Err(error), _) | (_, Err(error)) => Err(error),
+                };
+
+                let joined = json::JsonValue::Array(match joined {
+                    Ok(joined) => joined,
+                    Err(error) => return FlowData::Error(error),
+                });
+
+                match self.output.as_ref() {
+                    "json" => FlowData::Json(joined),
+                    "table" => match records::json_to_table(&joined) {
+                        Ok(table) => FlowData::Table(table),
+                        Err(error) => FlowData::Error(error),
+                    },
+                    other => FlowData::Error(format!("Unknown output '{}'", other)),
+                }
+            }
+            _ => FlowData::Error("Insufficient inputs".to_string()),
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, index: Option<i64>) -> () {
+        match index {
+            Some(1) => {
+                self.left_input = node;
+            }
+            Some(2) => {
+                self.right_input = node;
+            }
+            Some(_) => println!("Invalid input index for join-records"),
+            None => println!("Missing input index for join-records"),
+        }
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::List(vec![
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Mode (inner, left, right or full)"),
+                field: String::from("mode"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Left key (json path or column)"),
+                field: String::from("left-key"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Right key (defaults to the left key)"),
+                field: String::from("right-key"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Conflicts (suffix, left, right or error)"),
+                field: String::from("conflicts"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Suffix"),
+                field: String::from("suffix"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Output (json or table)"),
+                field: String::from("output"),
+            }),
+        ])
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "mode" => NodeUIData::StringData(self.mode.clone()),
+            "left-key" => NodeUIData::StringData(self.left_key.clone()),
+            "right-key" => NodeUIData::StringData(self.right_key.clone()),
+            "conflicts" => NodeUIData::StringData(self.conflicts.clone()),
+            "suffix" => NodeUIData::StringData(self.suffix.clone()),
+            "output" => NodeUIData::StringData(self.output.clone()),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("mode", NodeUIData::StringData(string)) => {
+                self.mode = string;
+            }
+            ("left-key", NodeUIData::StringData(string)) => {
+                self.left_key = string;
+            }
+            ("right-key", NodeUIData::StringData(string)) => {
+                self.right_key = string;
+            }
+            ("conflicts", NodeUIData::StringData(string)) => {
+                self.conflicts = string;
+            }
+            ("suffix", NodeUIData::StringData(string)) => {
+                self.suffix = string;
+            }
+            ("output", NodeUIData::StringData(string)) => {
+                self.output = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("join-records"),
+            attributes: vec![
+                SpecAttribute::String(String::from("mode"), self.mode.clone()),
+                SpecAttribute::String(String::from("left-key"), self.left_key.clone()),
+                SpecAttribute::String(String::from("right-key"), self.right_key.clone()),
+                SpecAttribute::String(String::from("conflicts"), self.conflicts.clone()),
+                SpecAttribute::String(String::from("suffix"), self.suffix.clone()),
+                SpecAttribute::String(String::from("output"), self.output.clone()),
+            ],
+        }
+    }
+}
+
+pub struct JoinRecordsBuilder {}
+
+impl NodeBuilder for JoinRecordsBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "join-records" {
+            return Some(Rc::new(RefCell::new(JoinRecords {
+                id: id,
+                left_input: None,
+                right_input: None,
+                mode: String::from(entry["mode"].as_str().unwrap_or("inner")),
+                left_key: String::from(entry["left-key"].as_str().unwrap_or("")),
+                right_key: String::from(entry["right-key"].as_str().unwrap_or("")),
+                conflicts: String::from(entry["conflicts"].as_str().unwrap_or("suffix")),
+                suffix: String::from(entry["suffix"].as_str().unwrap_or("_right")),
+                output: String::from(entry["output"].as_str().unwrap_or("json")),
+            })));
+        }
+        None
+    }
+}
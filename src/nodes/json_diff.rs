@@ -0,0 +1,79 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use FlowData;
+
+use super::json_patch;
+
+pub struct JsonDiff {
+    pub id: i64,
+    pub from_input: Option<Rc<RefCell<Node>>>,
+    pub to_input: Option<Rc<RefCell<Node>>>,
+}
+
+impl Node for JsonDiff {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match (self.from_input.clone(), self.to_input.clone()) {
+            (Some(ref mut from_input), Some(ref mut to_input)) => {
+                let from_content = from_input.borrow_mut().pull();
+                let to_content = to_input.borrow_mut().pull();
+
+                return match (from_content, to_content) {
+                    (FlowData::Json(from), FlowData::Json(to)) => {
+                        FlowData::Json(json_patch::diff(&from, &to))
+                    }
+                    (FlowData::Error(string), _) => FlowData::Error(string),
+                    (_, FlowData::Error(string)) => FlowData::Error(string),
+                    _ => FlowData::Error("Incorrect inputs".to_string()),
+                };
+            }
+            _ => FlowData::Error("Insufficient inputs".to_string()),
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, index: Option<i64>) -> () {
+        match index {
+            Some(1) => {
+                self.from_input = node;
+            }
+            Some(2) => {
+                self.to_input = node;
+            }
+            Some(_) => println!("Invalid input index for json-diff"),
+            None => println!("Missing input index for json-diff"),
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("json-diff"),
+            attributes: vec![],
+        }
+    }
+}
+
+pub struct JsonDiffBuilder {}
+
+impl NodeBuilder for JsonDiffBuilder {
+    fn build(&self, id: i64, name: &str, _entry: &Yaml) -> Option<NodeRef> {
+        if name == "json-diff" {
+            return Some(Rc::new(RefCell::new(JsonDiff {
+                id: id,
+                from_input: None,
+                to_input: None,
+            })));
+        }
+        None
+    }
+}
@@ -0,0 +1,164 @@
+extern crate json;
+
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+pub struct JsonFlatten {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub separator: String,
+    pub arrays: String,
+}
+
+impl JsonFlatten {
+    fn join(&self, prefix: &Option<String>, key: &str) -> String {
+        match *prefix {
+            Some(ref prefix) => format!("{}{}{}", prefix, self.separator, key),
+            None => key.to_string(),
+        }
+    }
+
+    // Empty objects & arrays are kept as values so that they survive a round trip
+    fn flatten_into(
+        &self,
+        prefix: Option<String>,
+        value: &json::JsonValue,
+        output: &mut json::object::Object,
+    ) {
+        match *value {
+            json::JsonValue::Object(ref object) if !object.is_empty() => {
+                for (key, child) in object.iter() {
+                    self.flatten_into(Some(self.join(&prefix, key)), child, output);
+                }
+            }
+            json::JsonValue::Array(ref array) if !array.is_empty() && self.arrays != "keep" => {
+                // Indices are always bracketed so that numeric object keys stay keys on the way back
+                for (index, child) in array.iter().enumerate() {
+                    let key = format!("{}[{}]", prefix.clone().unwrap_or_default(), index);
+                    self.flatten_into(Some(key), child, output);
+                }
+            }
+            _ => {
+                output.insert(prefix.unwrap_or_default().as_str(), value.clone());
+            }
+        }
+    }
+
+    fn flatten(&self, value: &json::JsonValue) -> json::JsonValue {
+        if !value.is_object() {
+            return value.clone();
+        }
+        let mut output = json::object::Object::new();
+        self.flatten_into(None, value, &mut output);
+        json::JsonValue::Object(output)
+    }
+}
+
+impl Node for JsonFlatten {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                match self.arrays.as_ref() {
+                    "brackets" | "keep" => {}
+                    other => return FlowData::Error(format!("Unknown array handling '{}'", other)),
+                }
+
+                let content = input.borrow_mut().pull();
+
+                // Arrays are treated as a list of records with each one being flattened
+                return match content {
+                    FlowData::Json(data) => if data.is_array() {
+                        FlowData::Json(json::JsonValue::Array(
+                            data.members().map(|member| self.flatten(member)).collect(),
+                        ))
+                    } else {
+                        FlowData::Json(self.flatten(&data))
+                    },
+                    FlowData::Error(string) => FlowData::Error(string),
+                    _ => FlowData::Error("Unknown data".to_string()),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::List(vec![
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Separator"),
+                field: String::from("separator"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Arrays (brackets or keep)"),
+                field: String::from("arrays"),
+            }),
+        ])
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "separator" => NodeUIData::StringData(self.separator.clone()),
+            "arrays" => NodeUIData::StringData(self.arrays.clone()),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("separator", NodeUIData::StringData(string)) => {
+                self.separator = string;
+            }
+            ("arrays", NodeUIData::StringData(string)) => {
+                self.arrays = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("json-flatten"),
+            attributes: vec![
+                SpecAttribute::String(String::from("separator"), self.separator.clone()),
+                SpecAttribute::String(String::from("arrays"), self.arrays.clone()),
+            ],
+        }
+    }
+}
+
+pub struct JsonFlattenBuilder {}
+
+impl NodeBuilder for JsonFlattenBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "json-flatten" {
+            return Some(Rc::new(RefCell::new(JsonFlatten {
+                id: id,
+                input: None,
+                separator: String::from(entry["separator"].as_str().unwrap_or(".")),
+                arrays: String::from(entry["arrays"].as_str().unwrap_or("brackets")),
+            })));
+        }
+        None
+    }
+}
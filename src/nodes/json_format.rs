@@ -0,0 +1,159 @@
+use json::JsonValue;
+
+pub struct Options {
+    pub indent: usize,
+    pub sort_keys: bool,
+    pub ascii: bool,
+    pub canonical: bool,
+}
+
+// Numbers are written as ECMAScript would, as required by RFC 8785, which has no representation for
+// infinite or nan values
+fn canonical_number(value: f64) -> Result<String, String> {
+    if !value.is_finite() {
+        return Err(format!("Cannot represent '{}' in canonical json", value));
+    }
+    if value == 0.0 {
+        return Ok(String::from("0"));
+    }
+
+    let sign = if value < 0.0 { "-" } else { "" };
+
+    // The shortest round trip representation gives the digits & the decimal exponent
+    let scientific = format!("{:e}", value.abs());
+    let (mantissa, exponent) = match scientific.find('e') {
+        Some(position) => scientific.split_at(position),
+        None => return Err(format!("Cannot represent '{}' in canonical json", value)),
+    };
+    let digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+    let digits = digits.trim_end_matches('0');
+    let digits = if digits.is_empty() { "0" } else { digits };
+    let k = digits.len() as i32;
+    let n = match exponent[1..].parse::<i32>() {
+        Ok(exponent) => exponent + 1,
+        Err(_) => return Err(format!("Cannot represent '{}' in canonical json", value)),
+    };
+
+    let body = if k <= n && n <= 21 {
+        format!("{}{}", digits, "0".repeat((n - k) as usize))
+    } else if 0 < n && n <= 21 {
+        format!("{}.{}", &digits[..n as usize], &digits[n as usize..])
+    } else if -6 < n && n <= 0 {
+        format!("0.{}{}", "0".repeat(-n as usize), digits)
+    } else {
+        let exponent_sign = if n - 1 < 0 { "-" } else { "+" };
+        if k == 1 {
+            format!("{}e{}{}", digits, exponent_sign, (n - 1).abs())
+        } else {
+            format!(
+                "{}.{}e{}{}",
+                &digits[..1],
+                &digits[1..],
+                exponent_sign,
+                (n - 1).abs()
+            )
+        }
+    };
+
+    Ok(format!("{}{}", sign, body))
+}
+
+fn write_string(text: &str, options: &Options, output: &mut String) {
+    output.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            '\u{8}' => output.push_str("\\b"),
+            '\u{c}' => output.push_str("\\f"),
+            c if (c as u32) < 0x20 => output.push_str(&format!("\\u{:04x}", c as u32)),
+            c if options.ascii && !options.canonical && !c.is_ascii() => {
+                let mut buffer = [0; 2];
+                for unit in c.encode_utf16(&mut buffer) {
+                    output.push_str(&format!("\\u{:04x}", unit));
+                }
+            }
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+}
+
+fn write_newline(depth: usize, options: &Options, output: &mut String) {
+    if options.indent > 0 && !options.canonical {
+        output.push('\n');
+        output.push_str(&" ".repeat(options.indent * depth));
+    }
+}
+
+fn write_value(
+    value: &JsonValue,
+    depth: usize,
+    options: &Options,
+    output: &mut String,
+) -> Result<(), String> {
+    match *value {
+        JsonValue::Short(ref short) => write_string(short.as_str(), options, output),
+        JsonValue::String(ref string) => write_string(string.as_str(), options, output),
+        JsonValue::Number(_) if options.canonical => {
+            output.push_str(&canonical_number(value.as_f64().unwrap_or(0.0))?)
+        }
+        JsonValue::Array(ref array) => {
+            if array.is_empty() {
+                output.push_str("[]");
+                return Ok(());
+            }
+            output.push('[');
+            for (index, member) in array.iter().enumerate() {
+                if index > 0 {
+                    output.push(',');
+                }
+                write_newline(depth + 1, options, output);
+                write_value(member, depth + 1, options, output)?;
+            }
+            write_newline(depth, options, output);
+            output.push(']');
+        }
+        JsonValue::Object(ref object) => {
+            if object.is_empty() {
+                output.push_str("{}");
+                return Ok(());
+            }
+
+            let mut entries: Vec<(&str, &JsonValue)> = object.iter().collect();
+            if options.canonical {
+                // RFC 8785 orders keys by their UTF-16 code units
+                entries.sort_by(|a, b| a.0.encode_utf16().cmp(b.0.encode_utf16()));
+            } else if options.sort_keys {
+                entries.sort_by(|a, b| a.0.cmp(b.0));
+            }
+
+            output.push('{');
+            for (index, &(key, member)) in entries.iter().enumerate() {
+                if index > 0 {
+                    output.push(',');
+                }
+                write_newline(depth + 1, options, output);
+                write_string(key, options, output);
+                output.push(':');
+                if options.indent > 0 && !options.canonical {
+                    output.push(' ');
+                }
+                write_value(member, depth + 1, options, output)?;
+            }
+            write_newline(depth, options, output);
+            output.push('}');
+        }
+        ref other => output.push_str(&other.dump()),
+    }
+    Ok(())
+}
+
+pub fn format(value: &JsonValue, options: &Options) -> Result<String, String> {
+    let mut output = String::new();
+    write_value(value, 0, options, &mut output)?;
+    Ok(output)
+}
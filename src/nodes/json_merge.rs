@@ -0,0 +1,115 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::json_patch;
+
+pub struct JsonMerge {
+    pub id: i64,
+    pub base_input: Option<Rc<RefCell<Node>>>,
+    pub overlay_input: Option<Rc<RefCell<Node>>>,
+    pub arrays: String,
+}
+
+impl Node for JsonMerge {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match (self.base_input.clone(), self.overlay_input.clone()) {
+            (Some(ref mut base_input), Some(ref mut overlay_input)) => {
+                if !json_patch::ARRAY_STRATEGIES.contains(&self.arrays.as_str()) {
+                    return FlowData::Error(format!("Unknown array strategy '{}'", self.arrays));
+                }
+
+                let base_content = base_input.borrow_mut().pull();
+                let overlay_content = overlay_input.borrow_mut().pull();
+
+                return match (base_content, overlay_content) {
+                    (FlowData::Json(mut base), FlowData::Json(overlay)) => {
+                        json_patch::merge(&mut base, &overlay, self.arrays.as_str());
+                        FlowData::Json(base)
+                    }
+                    (FlowData::Error(string), _) => FlowData::Error(string),
+                    (_, FlowData::Error(string)) => FlowData::Error(string),
+                    _ => FlowData::Error("Incorrect inputs".to_string()),
+                };
+            }
+            _ => FlowData::Error("Insufficient inputs".to_string()),
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, index: Option<i64>) -> () {
+        match index {
+            Some(1) => {
+                self.base_input = node;
+            }
+            Some(2) => {
+                self.overlay_input = node;
+            }
+            Some(_) => println!("Invalid input index for json-merge"),
+            None => println!("Missing input index for json-merge"),
+        }
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::StringField(StringFieldData {
+            label: String::from("Arrays (replace, concat, union or merge)"),
+            field: String::from("arrays"),
+        })
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        if field == "arrays" {
+            return NodeUIData::StringData(self.arrays.clone());
+        }
+        NodeUIData::None
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("arrays", NodeUIData::StringData(string)) => {
+                self.arrays = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("json-merge"),
+            attributes: vec![
+                SpecAttribute::String(String::from("arrays"), self.arrays.clone()),
+            ],
+        }
+    }
+}
+
+pub struct JsonMergeBuilder {}
+
+impl NodeBuilder for JsonMergeBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "json-merge" {
+            return Some(Rc::new(RefCell::new(JsonMerge {
+                id: id,
+                base_input: None,
+                overlay_input: None,
+                arrays: String::from(entry["arrays"].as_str().unwrap_or("replace")),
+            })));
+        }
+        None
+    }
+}
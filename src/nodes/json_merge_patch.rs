@@ -0,0 +1,80 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use FlowData;
+
+use super::json_patch;
+
+pub struct JsonMergePatch {
+    pub id: i64,
+    pub target_input: Option<Rc<RefCell<Node>>>,
+    pub patch_input: Option<Rc<RefCell<Node>>>,
+}
+
+impl Node for JsonMergePatch {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match (self.target_input.clone(), self.patch_input.clone()) {
+            (Some(ref mut target_input), Some(ref mut patch_input)) => {
+                let target_content = target_input.borrow_mut().pull();
+                let patch_content = patch_input.borrow_mut().pull();
+
+                return match (target_content, patch_content) {
+                    (FlowData::Json(mut target), FlowData::Json(patch)) => {
+                        json_patch::merge_patch(&mut target, &patch);
+                        FlowData::Json(target)
+                    }
+                    (FlowData::Error(string), _) => FlowData::Error(string),
+                    (_, FlowData::Error(string)) => FlowData::Error(string),
+                    _ => FlowData::Error("Incorrect inputs".to_string()),
+                };
+            }
+            _ => FlowData::Error("Insufficient inputs".to_string()),
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, index: Option<i64>) -> () {
+        match index {
+            Some(1) => {
+                self.target_input = node;
+            }
+            Some(2) => {
+                self.patch_input = node;
+            }
+            Some(_) => println!("Invalid input index for json-merge-patch"),
+            None => println!("Missing input index for json-merge-patch"),
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("json-merge-patch"),
+            attributes: vec![],
+        }
+    }
+}
+
+pub struct JsonMergePatchBuilder {}
+
+impl NodeBuilder for JsonMergePatchBuilder {
+    fn build(&self, id: i64, name: &str, _entry: &Yaml) -> Option<NodeRef> {
+        if name == "json-merge-patch" {
+            return Some(Rc::new(RefCell::new(JsonMergePatch {
+                id: id,
+                target_input: None,
+                patch_input: None,
+            })));
+        }
+        None
+    }
+}
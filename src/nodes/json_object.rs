@@ -1,4 +1,3 @@
-
 extern crate json;
 
 use yaml_rust::Yaml;
@@ -7,15 +6,51 @@ use std::rc::Rc;
 use std::cell::RefCell;
 
 use Spec;
+use SpecAttribute;
 use Node;
 use NodeRef;
 use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use BoolFieldData;
 use FlowData;
 
+use super::records;
+
 pub struct JsonObject {
     pub id: i64,
     pub keys_input: Option<Rc<RefCell<Node>>>,
     pub values_input: Option<Rc<RefCell<Node>>>,
+    pub infer: bool,
+    pub mismatch: String,
+}
+
+impl JsonObject {
+    fn build_object(&self, keys: Vec<String>, mut values: Vec<json::JsonValue>) -> FlowData {
+        if keys.len() != values.len() {
+            match self.mismatch.as_ref() {
+                "truncate" => {}
+                "pad" => while values.len() < keys.len() {
+                    values.push(json::JsonValue::Null);
+                },
+                "error" => {
+                    return FlowData::Error(format!(
+                        "Mismatched keys & values: {} & {}",
+                        keys.len(),
+                        values.len()
+                    ))
+                }
+                other => return FlowData::Error(format!("Unknown mismatch policy '{}'", other)),
+            }
+        }
+
+        let mut object = json::object::Object::new();
+        for (key, value) in keys.iter().zip(values.into_iter()) {
+            object.insert(key, value);
+        }
+        FlowData::Json(json::JsonValue::Object(object))
+    }
 }
 
 impl Node for JsonObject {
@@ -29,17 +64,14 @@ impl Node for JsonObject {
                 let keys_content = keys_input.borrow_mut().pull();
                 let values_content = values_input.borrow_mut().pull();
 
-                println!("{:?}", keys_content);
-                println!("{:?}", values_content);
-
-                return match (keys_content, values_content) {
-                    (FlowData::StringArray(keys), FlowData::StringArray(values)) => {
-                        let mut object = json::object::Object::new();
-                        for (key, value) in keys.iter().zip(values.iter()) {
-                            object.insert(key, json::JsonValue::String(value.to_string()));
+                return match keys_content {
+                    FlowData::StringArray(keys) => {
+                        match records::array_values(values_content, self.infer) {
+                            Ok(values) => self.build_object(keys, values),
+                            Err(error) => FlowData::Error(error),
                         }
-                        return FlowData::Json(json::JsonValue::Object(object));
                     }
+                    FlowData::Error(string) => FlowData::Error(string),
                     _ => FlowData::Error("Incorrect inputs".to_string()),
                 };
             }
@@ -60,11 +92,47 @@ impl Node for JsonObject {
         }
     }
 
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::List(vec![
+            NodeUI::BoolField(BoolFieldData {
+                label: String::from("Infer value types"),
+                field: String::from("infer"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Mismatch (truncate, pad or error)"),
+                field: String::from("mismatch"),
+            }),
+        ])
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "infer" => NodeUIData::BoolData(self.infer),
+            "mismatch" => NodeUIData::StringData(self.mismatch.clone()),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("infer", NodeUIData::BoolData(value)) => {
+                self.infer = value;
+            }
+            ("mismatch", NodeUIData::StringData(string)) => {
+                self.mismatch = string;
+            }
+            _ => {}
+        }
+    }
+
     fn get_spec(&self) -> Spec {
         Spec {
             id: self.id,
             type_: String::from("json-object"),
-            attributes: vec![],
+            attributes: vec![
+                SpecAttribute::Bool(String::from("infer"), self.infer),
+                SpecAttribute::String(String::from("mismatch"), self.mismatch.clone()),
+            ],
         }
     }
 }
@@ -72,12 +140,14 @@ impl Node for JsonObject {
 pub struct JsonObjectBuilder {}
 
 impl NodeBuilder for JsonObjectBuilder {
-    fn build(&self, id: i64, name: &str, _entry: &Yaml) -> Option<NodeRef> {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
         if name == "json-object" {
             return Some(Rc::new(RefCell::new(JsonObject {
                 id: id,
                 keys_input: None,
                 values_input: None,
+                infer: entry["infer"].as_bool().unwrap_or(false),
+                mismatch: String::from(entry["mismatch"].as_str().unwrap_or("truncate")),
             })));
         }
         None
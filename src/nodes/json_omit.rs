@@ -0,0 +1,121 @@
+extern crate json;
+
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+pub struct JsonOmit {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub keys: String,
+}
+
+impl JsonOmit {
+    fn omit(&self, value: &mut json::JsonValue, keys: &Vec<&str>) {
+        if value.is_object() {
+            for key in keys {
+                value.remove(key);
+            }
+        }
+    }
+}
+
+impl Node for JsonOmit {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                // Keys are given as a comma separated list
+                let keys = self.keys
+                    .split(',')
+                    .map(|key| key.trim())
+                    .filter(|key| !key.is_empty())
+                    .collect();
+
+                return match content {
+                    FlowData::Json(mut data) => {
+                        if data.is_array() {
+                            for member in data.members_mut() {
+                                self.omit(member, &keys);
+                            }
+                        } else {
+                            self.omit(&mut data, &keys);
+                        }
+                        FlowData::Json(data)
+                    }
+                    FlowData::Error(string) => FlowData::Error(string),
+                    _ => FlowData::Error("Unknown data".to_string()),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::StringField(StringFieldData {
+            label: String::from("Keys"),
+            field: String::from("keys"),
+        })
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        if field == "keys" {
+            return NodeUIData::StringData(self.keys.clone());
+        }
+        NodeUIData::None
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("keys", NodeUIData::StringData(string)) => {
+                self.keys = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("json-omit"),
+            attributes: vec![
+                SpecAttribute::String(String::from("keys"), self.keys.clone()),
+            ],
+        }
+    }
+}
+
+pub struct JsonOmitBuilder {}
+
+impl NodeBuilder for JsonOmitBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "json-omit" {
+            return Some(Rc::new(RefCell::new(JsonOmit {
+                id: id,
+                input: None,
+                keys: String::from(entry["keys"].as_str().unwrap_or("")),
+            })));
+        }
+        None
+    }
+}
@@ -34,6 +34,21 @@ impl Node for JsonParse {
                             Err(_e) => FlowData::Error("Failed to parse json".to_string()),
                         }
                     }
+                    FlowData::StringArray(lines) => {
+                        let mut output = vec![];
+                        for (index, line) in lines.iter().enumerate() {
+                            match json::parse(line) {
+                                Ok(data) => output.push(data),
+                                Err(_e) => {
+                                    return FlowData::Error(format!(
+                                        "Failed to parse json at index {}",
+                                        index
+                                    ))
+                                }
+                            }
+                        }
+                        return FlowData::Json(json::JsonValue::Array(output));
+                    }
                     FlowData::Error(string) => FlowData::Error(string),
                     _ => FlowData::Error("Unknown data".to_string()),
                 };
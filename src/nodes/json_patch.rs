@@ -0,0 +1,296 @@
+use json::JsonValue;
+
+pub const ARRAY_STRATEGIES: [&'static str; 4] = ["replace", "concat", "union", "merge"];
+
+// Deep merges the overlay into the base. Arrays are replaced, concatenated, concatenated without
+// duplicates or merged index by index depending on the strategy.
+pub fn merge(base: &mut JsonValue, overlay: &JsonValue, arrays: &str) {
+    if base.is_object() && overlay.is_object() {
+        for (key, value) in overlay.entries() {
+            if base.has_key(key) {
+                merge(&mut base[key], value, arrays);
+            } else {
+                base[key] = value.clone();
+            }
+        }
+        return;
+    }
+
+    if let (&mut JsonValue::Array(ref mut base), &JsonValue::Array(ref overlay)) =
+        (&mut *base, overlay)
+    {
+        match arrays {
+            "concat" => {
+                base.extend(overlay.iter().cloned());
+                return;
+            }
+            "union" => {
+                for value in overlay {
+                    if !base.contains(value) {
+                        base.push(value.clone());
+                    }
+                }
+                return;
+            }
+            "merge" => {
+                for (index, value) in overlay.iter().enumerate() {
+                    if index < base.len() {
+                        merge(&mut base[index], value, arrays);
+                    } else {
+                        base.push(value.clone());
+                    }
+                }
+                return;
+            }
+            _ => {}
+        }
+    }
+
+    *base = overlay.clone();
+}
+
+// RFC 7396 JSON Merge Patch
+pub fn merge_patch(target: &mut JsonValue, patch: &JsonValue) {
+    if !patch.is_object() {
+        *target = patch.clone();
+        return;
+    }
+
+    if !target.is_object() {
+        *target = JsonValue::new_object();
+    }
+
+    for (key, value) in patch.entries() {
+        if value.is_null() {
+            target.remove(key);
+        } else {
+            merge_patch(&mut target[key], value);
+        }
+    }
+}
+
+pub fn escape_token(token: &str) -> String {
+    token.replace("~", "~0").replace("/", "~1")
+}
+
+fn pointer_tokens(pointer: &str) -> Result<Vec<String>, String> {
+    if pointer.is_empty() {
+        return Ok(vec![]);
+    }
+    if !pointer.starts_with('/') {
+        return Err(format!("Invalid pointer '{}'", pointer));
+    }
+    Ok(pointer[1..]
+        .split('/')
+        .map(|token| token.replace("~1", "/").replace("~0", "~"))
+        .collect())
+}
+
+fn array_index(token: &str, len: usize, allow_end: bool) -> Result<usize, String> {
+    if allow_end && token == "-" {
+        return Ok(len);
+    }
+    match token.parse::<usize>() {
+        Ok(index) if index < len || (allow_end && index == len) => Ok(index),
+        _ => Err(format!("Invalid array index '{}'", token)),
+    }
+}
+
+fn get<'a>(document: &'a JsonValue, tokens: &[String]) -> Result<&'a JsonValue, String> {
+    let mut current = document;
+    for token in tokens {
+        current = match *current {
+            JsonValue::Object(ref object) => match object.get(token.as_str()) {
+                Some(value) => value,
+                None => return Err(format!("No value at '{}'", token)),
+            },
+            JsonValue::Array(ref array) => &array[array_index(token, array.len(), false)?],
+            _ => return Err(format!("No value at '{}'", token)),
+        };
+    }
+    Ok(current)
+}
+
+fn get_mut<'a>(document: &'a mut JsonValue, tokens: &[String]) -> Result<&'a mut JsonValue, String> {
+    let mut current = document;
+    for token in tokens {
+        current = match *current {
+            JsonValue::Object(ref mut object) => match object.get_mut(token.as_str()) {
+                Some(value) => value,
+                None => return Err(format!("No value at '{}'", token)),
+            },
+            JsonValue::Array(ref mut array) => {
+                let index = array_index(token, array.len(), false)?;
+                &mut array[index]
+            }
+            _ => return Err(format!("No value at '{}'", token)),
+        };
+    }
+    Ok(current)
+}
+
+fn add(document: &mut JsonValue, tokens: &[String], value: JsonValue) -> Result<(), String> {
+    let (last, parent) = match tokens.split_last() {
+        Some(split) => split,
+        None => {
+            *document = value;
+            return Ok(());
+        }
+    };
+
+    match *get_mut(document, parent)? {
+        JsonValue::Object(ref mut object) => {
+            object.insert(last.as_str(), value);
+            Ok(())
+        }
+        JsonValue::Array(ref mut array) => {
+            let index = array_index(last, array.len(), true)?;
+            array.insert(index, value);
+            Ok(())
+        }
+        _ => Err(format!("Cannot add '{}' to a value that is not a container", last)),
+    }
+}
+
+fn remove(document: &mut JsonValue, tokens: &[String]) -> Result<JsonValue, String> {
+    let (last, parent) = match tokens.split_last() {
+        Some(split) => split,
+        None => return Err("Cannot remove the whole document".to_string()),
+    };
+
+    match *get_mut(document, parent)? {
+        JsonValue::Object(ref mut object) => match object.remove(last.as_str()) {
+            Some(value) => Ok(value),
+            None => Err(format!("No value at '{}'", last)),
+        },
+        JsonValue::Array(ref mut array) => {
+            let index = array_index(last, array.len(), false)?;
+            Ok(array.remove(index))
+        }
+        _ => Err(format!("No value at '{}'", last)),
+    }
+}
+
+fn apply_operation(document: &mut JsonValue, operation: &JsonValue) -> Result<(), String> {
+    let path = match operation["path"].as_str() {
+        Some(path) => pointer_tokens(path)?,
+        None => return Err(format!("Missing 'path' in {}", operation.dump())),
+    };
+
+    let from = || match operation["from"].as_str() {
+        Some(from) => pointer_tokens(from),
+        None => Err(format!("Missing 'from' in {}", operation.dump())),
+    };
+
+    let value = || {
+        if operation.has_key("value") {
+            Ok(operation["value"].clone())
+        } else {
+            Err(format!("Missing 'value' in {}", operation.dump()))
+        }
+    };
+
+    match operation["op"].as_str() {
+        Some("add") => add(document, &path, value()?),
+        Some("remove") => remove(document, &path).map(|_| ()),
+        Some("replace") => {
+            *get_mut(document, &path)? = value()?;
+            Ok(())
+        }
+        Some("move") => {
+            let from = from()?;
+            if path.len() > from.len() && path[..from.len()] == from[..] {
+                return Err("Cannot move a value into one of its children".to_string());
+            }
+            let moved = remove(document, &from)?;
+            add(document, &path, moved)
+        }
+        Some("copy") => {
+            let copied = get(document, &from()?)?.clone();
+            add(document, &path, copied)
+        }
+        Some("test") => {
+            if *get(document, &path)? == value()? {
+                Ok(())
+            } else {
+                Err(format!("Test failed: {}", operation.dump()))
+            }
+        }
+        Some(other) => Err(format!("Unknown patch operation '{}'", other)),
+        None => Err(format!("Missing 'op' in {}", operation.dump())),
+    }
+}
+
+// RFC 6902 JSON Patch. Operations are applied to a copy so a failure leaves nothing half applied.
+pub fn apply_patch(document: &JsonValue, patch: &JsonValue) -> Result<JsonValue, String> {
+    if !patch.is_array() {
+        return Err("Expected the patch to be an array of operations".to_string());
+    }
+
+    let mut output = document.clone();
+    for operation in patch.members() {
+        apply_operation(&mut output, operation)?;
+    }
+    Ok(output)
+}
+
+fn operation(op: &str, path: &str, value: Option<&JsonValue>) -> JsonValue {
+    let mut object = JsonValue::new_object();
+    object["op"] = JsonValue::from(op);
+    object["path"] = JsonValue::from(path);
+    if let Some(value) = value {
+        object["value"] = value.clone();
+    }
+    object
+}
+
+fn diff_into(from: &JsonValue, to: &JsonValue, path: &str, operations: &mut Vec<JsonValue>) {
+    if from == to {
+        return;
+    }
+
+    if from.is_object() && to.is_object() {
+        for (key, _) in from.entries() {
+            if !to.has_key(key) {
+                let child = format!("{}/{}", path, escape_token(key));
+                operations.push(operation("remove", child.as_str(), None));
+            }
+        }
+        for (key, value) in to.entries() {
+            let child = format!("{}/{}", path, escape_token(key));
+            if from.has_key(key) {
+                diff_into(&from[key], value, child.as_str(), operations);
+            } else {
+                operations.push(operation("add", child.as_str(), Some(value)));
+            }
+        }
+        return;
+    }
+
+    if from.is_array() && to.is_array() {
+        let common = from.len().min(to.len());
+        for index in 0..common {
+            let child = format!("{}/{}", path, index);
+            diff_into(&from[index], &to[index], child.as_str(), operations);
+        }
+        // Removed from the end first so that the earlier indices stay valid
+        for index in (common..from.len()).rev() {
+            let child = format!("{}/{}", path, index);
+            operations.push(operation("remove", child.as_str(), None));
+        }
+        for index in common..to.len() {
+            let child = format!("{}/{}", path, index);
+            operations.push(operation("add", child.as_str(), Some(&to[index])));
+        }
+        return;
+    }
+
+    operations.push(operation("replace", path, Some(to)));
+}
+
+// Produces an RFC 6902 patch which turns 'from' into 'to'
+pub fn diff(from: &JsonValue, to: &JsonValue) -> JsonValue {
+    let mut operations = vec![];
+    diff_into(from, to, "", &mut operations);
+    JsonValue::Array(operations)
+}
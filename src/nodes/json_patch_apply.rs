@@ -0,0 +1,82 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use FlowData;
+
+use super::json_patch;
+
+pub struct JsonPatch {
+    pub id: i64,
+    pub document_input: Option<Rc<RefCell<Node>>>,
+    pub patch_input: Option<Rc<RefCell<Node>>>,
+}
+
+impl Node for JsonPatch {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match (self.document_input.clone(), self.patch_input.clone()) {
+            (Some(ref mut document_input), Some(ref mut patch_input)) => {
+                let document_content = document_input.borrow_mut().pull();
+                let patch_content = patch_input.borrow_mut().pull();
+
+                return match (document_content, patch_content) {
+                    (FlowData::Json(document), FlowData::Json(patch)) => {
+                        match json_patch::apply_patch(&document, &patch) {
+                            Ok(output) => FlowData::Json(output),
+                            Err(error) => FlowData::Error(error),
+                        }
+                    }
+                    (FlowData::Error(string), _) => FlowData::Error(string),
+                    (_, FlowData::Error(string)) => FlowData::Error(string),
+                    _ => FlowData::Error("Incorrect inputs".to_string()),
+                };
+            }
+            _ => FlowData::Error("Insufficient inputs".to_string()),
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, index: Option<i64>) -> () {
+        match index {
+            Some(1) => {
+                self.document_input = node;
+            }
+            Some(2) => {
+                self.patch_input = node;
+            }
+            Some(_) => println!("Invalid input index for json-patch"),
+            None => println!("Missing input index for json-patch"),
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("json-patch"),
+            attributes: vec![],
+        }
+    }
+}
+
+pub struct JsonPatchBuilder {}
+
+impl NodeBuilder for JsonPatchBuilder {
+    fn build(&self, id: i64, name: &str, _entry: &Yaml) -> Option<NodeRef> {
+        if name == "json-patch" {
+            return Some(Rc::new(RefCell::new(JsonPatch {
+                id: id,
+                document_input: None,
+                patch_input: None,
+            })));
+        }
+        None
+    }
+}
@@ -0,0 +1,192 @@
+extern crate json;
+
+use FlowData;
+
+use super::records;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Segment {
+    Child(String),
+    Index(i64),
+    Wildcard,
+    Descendants,
+}
+
+fn pointer_token(token: &str) -> String {
+    token.replace("~1", "/").replace("~0", "~")
+}
+
+// Expressions starting with '/' are treated as JSON Pointers, eg. "/users/0/name", anything else as
+// JSONPath, eg. "$.users[*].name" or "$..name". The leading '$' is optional.
+pub fn parse(expression: &str) -> Result<Vec<Segment>, String> {
+    let expression = expression.trim();
+
+    if expression.is_empty() {
+        return Ok(vec![]);
+    }
+
+    if expression.starts_with('/') {
+        return Ok(expression[1..]
+            .split('/')
+            .map(|token| Segment::Child(pointer_token(token)))
+            .collect());
+    }
+
+    let error = |message: &str| format!("Invalid path '{}': {}", expression, message);
+
+    let chars: Vec<char> = expression.chars().collect();
+    let mut segments = vec![];
+    let mut index = 0;
+
+    if chars[0] == '$' {
+        index = 1;
+    }
+
+    // Allows paths like 'users.name' to omit the leading '$.'
+    let mut expect_name = index == 0 && chars[0] != '[' && chars[0] != '.';
+
+    while index < chars.len() || expect_name {
+        if expect_name {
+            let start = index;
+            while index < chars.len() && chars[index] != '.' && chars[index] != '[' {
+                index += 1;
+            }
+            let name: String = chars[start..index].iter().collect();
+            if name.is_empty() {
+                return Err(error("expected a key"));
+            } else if name == "*" {
+                segments.push(Segment::Wildcard);
+            } else {
+                segments.push(Segment::Child(name));
+            }
+            expect_name = false;
+            continue;
+        }
+
+        match chars[index] {
+            '.' => {
+                index += 1;
+                if index < chars.len() && chars[index] == '.' {
+                    segments.push(Segment::Descendants);
+                    index += 1;
+                }
+                expect_name = index >= chars.len() || chars[index] != '[';
+            }
+            '[' => {
+                let start = index + 1;
+                let mut end = start;
+                let mut quote = None;
+                while end < chars.len() {
+                    match (quote, chars[end]) {
+                        (None, ']') => break,
+                        (None, c) if c == '\'' || c == '"' => quote = Some(c),
+                        (Some(q), c) if c == q => quote = None,
+                        _ => {}
+                    }
+                    end += 1;
+                }
+                if end >= chars.len() {
+                    return Err(error("unclosed '['"));
+                }
+
+                let content: String = chars[start..end].iter().collect();
+                let content = content.trim();
+                if content == "*" {
+                    segments.push(Segment::Wildcard);
+                } else if content.len() >= 2
+                    && (content.starts_with('\'') || content.starts_with('"'))
+                    && content.ends_with(&content[..1])
+                {
+                    segments.push(Segment::Child(content[1..content.len() - 1].to_string()));
+                } else {
+                    match content.parse::<i64>() {
+                        Ok(number) => segments.push(Segment::Index(number)),
+                        Err(_) => return Err(error("expected an index, quoted key or '*'")),
+                    }
+                }
+                index = end + 1;
+            }
+            _ => return Err(error("expected '.' or '['")),
+        }
+    }
+
+    Ok(segments)
+}
+
+fn descendants<'a>(value: &'a json::JsonValue, output: &mut Vec<&'a json::JsonValue>) {
+    output.push(value);
+    match *value {
+        json::JsonValue::Object(ref object) => for (_, child) in object.iter() {
+            descendants(child, output);
+        },
+        json::JsonValue::Array(ref array) => for child in array.iter() {
+            descendants(child, output);
+        },
+        _ => {}
+    }
+}
+
+pub fn query<'a>(value: &'a json::JsonValue, segments: &[Segment]) -> Vec<&'a json::JsonValue> {
+    let mut current = vec![value];
+
+    for segment in segments {
+        let mut next = vec![];
+        for value in current {
+            match (segment, value) {
+                (&Segment::Child(ref key), &json::JsonValue::Object(ref object)) => {
+                    if let Some(child) = object.get(key.as_str()) {
+                        next.push(child);
+                    }
+                }
+                (&Segment::Child(ref key), &json::JsonValue::Array(ref array)) => {
+                    if let Some(child) = key.parse::<usize>().ok().and_then(|i| array.get(i)) {
+                        next.push(child);
+                    }
+                }
+                (&Segment::Index(number), &json::JsonValue::Array(ref array)) => {
+                    // Negative indices count back from the end of the array
+                    let position = if number < 0 {
+                        array.len() as i64 + number
+                    } else {
+                        number
+                    };
+                    if position >= 0 {
+                        if let Some(child) = array.get(position as usize) {
+                            next.push(child);
+                        }
+                    }
+                }
+                (&Segment::Wildcard, &json::JsonValue::Object(ref object)) => {
+                    next.extend(object.iter().map(|(_, child)| child));
+                }
+                (&Segment::Wildcard, &json::JsonValue::Array(ref array)) => {
+                    next.extend(array.iter());
+                }
+                (&Segment::Descendants, value) => descendants(value, &mut next),
+                _ => {}
+            }
+        }
+        current = next;
+    }
+
+    current
+}
+
+// Converts matched values to the requested output type: 'json' for an array, 'strings' or 'ints'
+pub fn to_output(values: Vec<json::JsonValue>, output: &str) -> FlowData {
+    match output {
+        "strings" => FlowData::StringArray(values.iter().map(records::cell_text).collect()),
+        "ints" => {
+            let mut ints = vec![];
+            for value in &values {
+                match value.as_i64() {
+                    Some(int) => ints.push(int),
+                    None => return FlowData::Error(format!("'{}' is not an integer", value.dump())),
+                }
+            }
+            FlowData::IntArray(ints)
+        }
+        "json" | "" => FlowData::Json(json::JsonValue::Array(values)),
+        other => FlowData::Error(format!("Unknown output type '{}'", other)),
+    }
+}
@@ -0,0 +1,119 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::json_path;
+
+pub struct JsonQuery {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub path: String,
+    pub output: String,
+}
+
+impl Node for JsonQuery {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let segments = match json_path::parse(self.path.as_str()) {
+                    Ok(segments) => segments,
+                    Err(error) => return FlowData::Error(error),
+                };
+
+                let content = input.borrow_mut().pull();
+
+                return match content {
+                    FlowData::Json(data) => {
+                        let values = json_path::query(&data, &segments)
+                            .into_iter()
+                            .cloned()
+                            .collect();
+                        json_path::to_output(values, self.output.as_str())
+                    }
+                    FlowData::Error(string) => FlowData::Error(string),
+                    _ => FlowData::Error("Unknown data".to_string()),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::List(vec![
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Path"),
+                field: String::from("path"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Output (json, strings or ints)"),
+                field: String::from("output"),
+            }),
+        ])
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "path" => NodeUIData::StringData(self.path.clone()),
+            "output" => NodeUIData::StringData(self.output.clone()),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("path", NodeUIData::StringData(string)) => {
+                self.path = string;
+            }
+            ("output", NodeUIData::StringData(string)) => {
+                self.output = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("json-query"),
+            attributes: vec![
+                SpecAttribute::String(String::from("path"), self.path.clone()),
+                SpecAttribute::String(String::from("output"), self.output.clone()),
+            ],
+        }
+    }
+}
+
+pub struct JsonQueryBuilder {}
+
+impl NodeBuilder for JsonQueryBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "json-query" {
+            return Some(Rc::new(RefCell::new(JsonQuery {
+                id: id,
+                input: None,
+                path: String::from(entry["path"].as_str().unwrap_or("$")),
+                output: String::from(entry["output"].as_str().unwrap_or("json")),
+            })));
+        }
+        None
+    }
+}
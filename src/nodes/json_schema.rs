@@ -0,0 +1,383 @@
+use json::JsonValue;
+use regex::Regex;
+
+use super::json_patch;
+
+// Guards against schemas whose references loop back on themselves
+const MAX_DEPTH: usize = 64;
+
+pub struct Violation {
+    pub pointer: String,
+    pub message: String,
+}
+
+impl Violation {
+    pub fn to_json(&self) -> JsonValue {
+        let mut object = JsonValue::new_object();
+        object["pointer"] = JsonValue::from(self.pointer.as_str());
+        object["message"] = JsonValue::from(self.message.as_str());
+        object
+    }
+}
+
+fn type_name(value: &JsonValue) -> &'static str {
+    match *value {
+        JsonValue::Null => "null",
+        JsonValue::Boolean(_) => "boolean",
+        JsonValue::Number(_) => if is_integer(value) {
+            "integer"
+        } else {
+            "number"
+        },
+        JsonValue::Short(_) | JsonValue::String(_) => "string",
+        JsonValue::Array(_) => "array",
+        JsonValue::Object(_) => "object",
+    }
+}
+
+fn is_integer(value: &JsonValue) -> bool {
+    value.as_f64().map_or(false, |number| number.fract() == 0.0)
+}
+
+fn matches_type(value: &JsonValue, name: &str) -> bool {
+    let actual = type_name(value);
+    actual == name || (name == "number" && actual == "integer")
+}
+
+fn compile(pattern: &str) -> Result<Regex, String> {
+    Regex::new(pattern).map_err(|error| format!("Invalid pattern '{}' in schema: {}", pattern, error))
+}
+
+struct Validator<'a> {
+    root: &'a JsonValue,
+}
+
+impl<'a> Validator<'a> {
+    // Only references within the schema itself are supported, eg. "#/definitions/address"
+    fn resolve(&self, reference: &str) -> Result<&'a JsonValue, String> {
+        if !reference.starts_with('#') {
+            return Err(format!("Unsupported schema reference '{}'", reference));
+        }
+
+        let mut current = self.root;
+        let pointer = &reference[1..];
+        if pointer.is_empty() {
+            return Ok(current);
+        }
+
+        for token in pointer[1..].split('/') {
+            let token = token.replace("~1", "/").replace("~0", "~");
+            current = match *current {
+                JsonValue::Object(ref object) => object.get(token.as_str()),
+                JsonValue::Array(ref array) => token.parse::<usize>().ok().and_then(|i| array.get(i)),
+                _ => None,
+            }.ok_or(format!("Unresolved schema reference '{}'", reference))?;
+        }
+        Ok(current)
+    }
+
+    fn is_valid(&self, schema: &'a JsonValue, value: &JsonValue, depth: usize) -> Result<bool, String> {
+        let mut violations = vec![];
+        self.check(schema, value, "", depth, &mut violations)?;
+        Ok(violations.is_empty())
+    }
+
+    fn check(
+        &self,
+        schema: &'a JsonValue,
+        value: &JsonValue,
+        pointer: &str,
+        depth: usize,
+        violations: &mut Vec<Violation>,
+    ) -> Result<(), String> {
+        if depth > MAX_DEPTH {
+            return Err("Schema references are nested too deeply".to_string());
+        }
+
+        let mut fail = |message: String| {
+            violations.push(Violation {
+                pointer: pointer.to_string(),
+                message: message,
+            })
+        };
+
+        match *schema {
+            JsonValue::Boolean(true) => return Ok(()),
+            JsonValue::Boolean(false) => {
+                fail("No value is allowed here".to_string());
+                return Ok(());
+            }
+            JsonValue::Object(_) => {}
+            _ => return Err(format!("Invalid schema: {}", schema.dump())),
+        }
+
+        if let Some(reference) = schema["$ref"].as_str() {
+            let target = self.resolve(reference)?;
+            return self.check(target, value, pointer, depth + 1, violations);
+        }
+
+        // Type & value constraints
+        match schema["type"] {
+            JsonValue::Null => {}
+            ref types => {
+                let names: Vec<&str> = if types.is_array() {
+                    types.members().filter_map(|name| name.as_str()).collect()
+                } else {
+                    types.as_str().into_iter().collect()
+                };
+                if !names.iter().any(|name| matches_type(value, name)) {
+                    fail(format!(
+                        "Expected {} but found {}",
+                        names.join(" or "),
+                        type_name(value)
+                    ));
+                }
+            }
+        }
+
+        if schema.has_key("const") && schema["const"] != *value {
+            fail(format!("Expected {}", schema["const"].dump()));
+        }
+
+        if schema["enum"].is_array() && !schema["enum"].members().any(|option| option == value) {
+            fail(format!("Expected one of {}", schema["enum"].dump()));
+        }
+
+        // Numbers
+        if let Some(number) = value.as_f64() {
+            if let Some(minimum) = schema["minimum"].as_f64() {
+                if number < minimum {
+                    fail(format!("Expected at least {}", minimum));
+                }
+            }
+            if let Some(maximum) = schema["maximum"].as_f64() {
+                if number > maximum {
+                    fail(format!("Expected at most {}", maximum));
+                }
+            }
+            if let Some(minimum) = schema["exclusiveMinimum"].as_f64() {
+                if number <= minimum {
+                    fail(format!("Expected more than {}", minimum));
+                }
+            }
+            if let Some(maximum) = schema["exclusiveMaximum"].as_f64() {
+                if number >= maximum {
+                    fail(format!("Expected less than {}", maximum));
+                }
+            }
+            if let Some(divisor) = schema["multipleOf"].as_f64() {
+                if divisor > 0.0 && (number / divisor).fract() != 0.0 {
+                    fail(format!("Expected a multiple of {}", divisor));
+                }
+            }
+        }
+
+        // Strings
+        if let Some(string) = value.as_str() {
+            let length = string.chars().count();
+            if let Some(minimum) = schema["minLength"].as_usize() {
+                if length < minimum {
+                    fail(format!("Expected at least {} characters", minimum));
+                }
+            }
+            if let Some(maximum) = schema["maxLength"].as_usize() {
+                if length > maximum {
+                    fail(format!("Expected at most {} characters", maximum));
+                }
+            }
+            if let Some(pattern) = schema["pattern"].as_str() {
+                if !compile(pattern)?.is_match(string) {
+                    fail(format!("Expected to match '{}'", pattern));
+                }
+            }
+        }
+
+        // Arrays
+        if let JsonValue::Array(ref array) = *value {
+            if let Some(minimum) = schema["minItems"].as_usize() {
+                if array.len() < minimum {
+                    fail(format!("Expected at least {} items", minimum));
+                }
+            }
+            if let Some(maximum) = schema["maxItems"].as_usize() {
+                if array.len() > maximum {
+                    fail(format!("Expected at most {} items", maximum));
+                }
+            }
+            if schema["uniqueItems"].as_bool() == Some(true) {
+                let duplicate = array
+                    .iter()
+                    .enumerate()
+                    .any(|(index, item)| array[..index].contains(item));
+                if duplicate {
+                    fail("Expected unique items".to_string());
+                }
+            }
+
+            // 'items' is either a schema for every item or a list of schemas by position with
+            // 'additionalItems' covering the rest
+            let items = &schema["items"];
+            for (index, item) in array.iter().enumerate() {
+                let child = format!("{}/{}", pointer, index);
+                let item_schema = if items.is_array() {
+                    if index < items.len() {
+                        &items[index]
+                    } else {
+                        &schema["additionalItems"]
+                    }
+                } else {
+                    items
+                };
+                if !item_schema.is_null() {
+                    self.check(item_schema, item, child.as_str(), depth + 1, violations)?;
+                }
+            }
+
+            if schema.has_key("contains") {
+                let mut found = false;
+                for item in array {
+                    if self.is_valid(&schema["contains"], item, depth + 1)? {
+                        found = true;
+                        break;
+                    }
+                }
+                if !found {
+                    violations.push(Violation {
+                        pointer: pointer.to_string(),
+                        message: "Expected an item matching 'contains'".to_string(),
+                    });
+                }
+            }
+        }
+
+        // Objects
+        if let JsonValue::Object(ref object) = *value {
+            let mut fail = |message: String| {
+                violations.push(Violation {
+                    pointer: pointer.to_string(),
+                    message: message,
+                })
+            };
+
+            if let Some(minimum) = schema["minProperties"].as_usize() {
+                if object.len() < minimum {
+                    fail(format!("Expected at least {} properties", minimum));
+                }
+            }
+            if let Some(maximum) = schema["maxProperties"].as_usize() {
+                if object.len() > maximum {
+                    fail(format!("Expected at most {} properties", maximum));
+                }
+            }
+            for required in schema["required"].members() {
+                if let Some(key) = required.as_str() {
+                    if object.get(key).is_none() {
+                        fail(format!("Missing required property '{}'", key));
+                    }
+                }
+            }
+
+            let mut patterns = vec![];
+            for (pattern, pattern_schema) in schema["patternProperties"].entries() {
+                patterns.push((compile(pattern)?, pattern_schema));
+            }
+
+            for (key, child_value) in object.iter() {
+                let child = format!("{}/{}", pointer, json_patch::escape_token(key));
+                let mut matched = false;
+
+                if schema["properties"].has_key(key) {
+                    matched = true;
+                    let property = &schema["properties"][key];
+                    self.check(property, child_value, child.as_str(), depth + 1, violations)?;
+                }
+
+                for &(ref regex, pattern_schema) in &patterns {
+                    if regex.is_match(key) {
+                        matched = true;
+                        self.check(pattern_schema, child_value, child.as_str(), depth + 1, violations)?;
+                    }
+                }
+
+                if !matched && schema.has_key("additionalProperties") {
+                    match schema["additionalProperties"] {
+                        JsonValue::Boolean(false) => violations.push(Violation {
+                            pointer: child.clone(),
+                            message: format!("Unexpected property '{}'", key),
+                        }),
+                        ref additional => {
+                            self.check(additional, child_value, child.as_str(), depth + 1, violations)?
+                        }
+                    }
+                }
+
+                if schema.has_key("propertyNames") {
+                    let name = JsonValue::from(key);
+                    if !self.is_valid(&schema["propertyNames"], &name, depth + 1)? {
+                        violations.push(Violation {
+                            pointer: child.clone(),
+                            message: format!("Invalid property name '{}'", key),
+                        });
+                    }
+                }
+            }
+        }
+
+        // Combinations
+        for sub_schema in schema["allOf"].members() {
+            self.check(sub_schema, value, pointer, depth + 1, violations)?;
+        }
+
+        if schema["anyOf"].is_array() {
+            let mut any = false;
+            for sub_schema in schema["anyOf"].members() {
+                if self.is_valid(sub_schema, value, depth + 1)? {
+                    any = true;
+                    break;
+                }
+            }
+            if !any {
+                violations.push(Violation {
+                    pointer: pointer.to_string(),
+                    message: "Expected to match at least one schema in 'anyOf'".to_string(),
+                });
+            }
+        }
+
+        if schema["oneOf"].is_array() {
+            let mut count = 0;
+            for sub_schema in schema["oneOf"].members() {
+                if self.is_valid(sub_schema, value, depth + 1)? {
+                    count += 1;
+                }
+            }
+            if count != 1 {
+                violations.push(Violation {
+                    pointer: pointer.to_string(),
+                    message: format!(
+                        "Expected to match exactly one schema in 'oneOf' but matched {}",
+                        count
+                    ),
+                });
+            }
+        }
+
+        if schema.has_key("not") && self.is_valid(&schema["not"], value, depth + 1)? {
+            violations.push(Violation {
+                pointer: pointer.to_string(),
+                message: "Expected not to match the schema in 'not'".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+// Validates against a JSON Schema, supporting the common keywords of draft 7. Problems with the
+// schema itself are returned as errors rather than violations.
+pub fn validate(schema: &JsonValue, value: &JsonValue) -> Result<Vec<Violation>, String> {
+    let validator = Validator { root: schema };
+    let mut violations = vec![];
+    validator.check(schema, value, "", 0, &mut violations)?;
+    Ok(violations)
+}
@@ -1,20 +1,29 @@
 
-extern crate json;
-
 use yaml_rust::Yaml;
 
 use std::rc::Rc;
 use std::cell::RefCell;
 
 use Spec;
+use SpecAttribute;
 use Node;
 use NodeRef;
 use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use BoolFieldData;
 use FlowData;
 
+use super::json_format;
+
 pub struct JsonStringify {
     pub id: i64,
     pub input: Option<Rc<RefCell<Node>>>,
+    pub indent: i64,
+    pub sort_keys: bool,
+    pub ascii: bool,
+    pub canonical: bool,
 }
 
 impl Node for JsonStringify {
@@ -29,7 +38,18 @@ impl Node for JsonStringify {
                 let content = input.borrow_mut().pull();
 
                 return match content {
-                    FlowData::Json(data) => FlowData::String(json::stringify(data)),
+                    FlowData::Json(data) => {
+                        let options = json_format::Options {
+                            indent: self.indent.max(0) as usize,
+                            sort_keys: self.sort_keys,
+                            ascii: self.ascii,
+                            canonical: self.canonical,
+                        };
+                        match json_format::format(&data, &options) {
+                            Ok(text) => FlowData::String(text),
+                            Err(error) => FlowData::Error(error),
+                        }
+                    }
                     FlowData::Error(string) => FlowData::Error(string),
                     _ => FlowData::Error("Unknown data".to_string()),
                 };
@@ -41,11 +61,70 @@ impl Node for JsonStringify {
         self.input = node;
     }
 
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::List(vec![
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Indent"),
+                field: String::from("indent"),
+            }),
+            NodeUI::BoolField(BoolFieldData {
+                label: String::from("Sort keys"),
+                field: String::from("sort-keys"),
+            }),
+            NodeUI::BoolField(BoolFieldData {
+                label: String::from("Escape non-ASCII"),
+                field: String::from("ascii"),
+            }),
+            NodeUI::BoolField(BoolFieldData {
+                label: String::from("Canonical"),
+                field: String::from("canonical"),
+            }),
+        ])
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "indent" => NodeUIData::StringData(self.indent.to_string()),
+            "sort-keys" => NodeUIData::BoolData(self.sort_keys),
+            "ascii" => NodeUIData::BoolData(self.ascii),
+            "canonical" => NodeUIData::BoolData(self.canonical),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("indent", NodeUIData::StringData(string)) => {
+                // An empty field is treated as no indentation so that it can be retyped
+                if string.trim().is_empty() {
+                    self.indent = 0;
+                } else if let Ok(indent) = string.trim().parse::<i64>() {
+                    self.indent = indent.max(0);
+                }
+            }
+            ("sort-keys", NodeUIData::BoolData(value)) => {
+                self.sort_keys = value;
+            }
+            ("ascii", NodeUIData::BoolData(value)) => {
+                self.ascii = value;
+            }
+            ("canonical", NodeUIData::BoolData(value)) => {
+                self.canonical = value;
+            }
+            _ => {}
+        }
+    }
+
     fn get_spec(&self) -> Spec {
         Spec {
             id: self.id,
             type_: String::from("json-stringify"),
-            attributes: vec![],
+            attributes: vec![
+                SpecAttribute::Int(String::from("indent"), self.indent),
+                SpecAttribute::Bool(String::from("sort-keys"), self.sort_keys),
+                SpecAttribute::Bool(String::from("ascii"), self.ascii),
+                SpecAttribute::Bool(String::from("canonical"), self.canonical),
+            ],
         }
     }
 }
@@ -53,11 +132,15 @@ impl Node for JsonStringify {
 pub struct JsonStringifyBuilder {}
 
 impl NodeBuilder for JsonStringifyBuilder {
-    fn build(&self, id: i64, name: &str, _entry: &Yaml) -> Option<NodeRef> {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
         if name == "json-stringify" {
             return Some(Rc::new(RefCell::new(JsonStringify {
                 id: id,
                 input: None,
+                indent: entry["indent"].as_i64().unwrap_or(0),
+                sort_keys: entry["sort-keys"].as_bool().unwrap_or(false),
+                ascii: entry["ascii"].as_bool().unwrap_or(false),
+                canonical: entry["canonical"].as_bool().unwrap_or(false),
             })));
         }
         None
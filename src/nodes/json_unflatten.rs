@@ -0,0 +1,235 @@
+extern crate json;
+
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+fn bracket_indices(text: &str) -> Option<Vec<usize>> {
+    let mut indices = vec![];
+    let mut rest = text;
+    while !rest.is_empty() {
+        if !rest.starts_with('[') {
+            return None;
+        }
+        let close = rest.find(']')?;
+        indices.push(rest[1..close].parse::<usize>().ok()?);
+        rest = &rest[close + 1..];
+    }
+    Some(indices)
+}
+
+pub struct JsonUnflatten {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub separator: String,
+    pub arrays: String,
+}
+
+impl JsonUnflatten {
+    fn segments(&self, key: &str) -> Vec<Segment> {
+        let mut segments = vec![];
+        for part in key.split(self.separator.as_str()) {
+            match self.arrays.as_ref() {
+                "brackets" => {
+                    // Splits 'name[0][1]' into the key followed by each index
+                    let name_end = part.find('[').unwrap_or(part.len());
+                    match bracket_indices(&part[name_end..]) {
+                        Some(indices) => {
+                            if name_end > 0 {
+                                segments.push(Segment::Key(part[..name_end].to_string()));
+                            }
+                            segments.extend(indices.into_iter().map(Segment::Index));
+                        }
+                        None => segments.push(Segment::Key(part.to_string())),
+                    }
+                }
+                _ => segments.push(Segment::Key(part.to_string())),
+            }
+        }
+        segments
+    }
+
+    // Keys may arrive in any order so arrays can have gaps while they are built, but no flattened
+    // array has more entries than there are keys, which limits the indices
+    fn insert(
+        &self,
+        target: &mut json::JsonValue,
+        segments: &[Segment],
+        value: json::JsonValue,
+        key: &str,
+        limit: usize,
+    ) -> Result<(), String> {
+        let (segment, rest) = match segments.split_first() {
+            Some(split) => split,
+            None => {
+                if !target.is_null() {
+                    return Err(format!("Conflicting key '{}'", key));
+                }
+                *target = value;
+                return Ok(());
+            }
+        };
+
+        if target.is_null() {
+            *target = match *segment {
+                Segment::Key(_) => json::JsonValue::new_object(),
+                Segment::Index(_) => json::JsonValue::new_array(),
+            };
+        }
+
+        match (segment, target) {
+            (&Segment::Key(ref name), target @ &mut json::JsonValue::Object(_)) => {
+                self.insert(&mut target[name.as_str()], rest, value, key, limit)
+            }
+            (&Segment::Index(index), target @ &mut json::JsonValue::Object(_)) => {
+                self.insert(&mut target[index.to_string().as_str()], rest, value, key, limit)
+            }
+            (&Segment::Index(index), &mut json::JsonValue::Array(ref mut array)) => {
+                if index >= limit {
+                    return Err(format!("Index {} is out of range in '{}'", index, key));
+                }
+                // Gaps in the indices are filled with null
+                while array.len() <= index {
+                    array.push(json::JsonValue::Null);
+                }
+                self.insert(&mut array[index], rest, value, key, limit)
+            }
+            _ => Err(format!("Conflicting key '{}'", key)),
+        }
+    }
+
+    fn unflatten(&self, value: &json::JsonValue) -> Result<json::JsonValue, String> {
+        if !value.is_object() {
+            return Ok(value.clone());
+        }
+
+        let mut output = json::JsonValue::new_object();
+        for (key, child) in value.entries() {
+            let segments = self.segments(key);
+            self.insert(&mut output, &segments, child.clone(), key, value.len())?;
+        }
+        Ok(output)
+    }
+}
+
+impl Node for JsonUnflatten {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                if self.separator.is_empty() {
+                    return FlowData::Error("No separator".to_string());
+                }
+
+                match self.arrays.as_ref() {
+                    "brackets" | "keep" => {}
+                    other => return FlowData::Error(format!("Unknown array handling '{}'", other)),
+                }
+
+                let content = input.borrow_mut().pull();
+
+                let result = match content {
+                    FlowData::Json(data) => if data.is_array() {
+                        data.members()
+                            .map(|member| self.unflatten(member))
+                            .collect::<Result<Vec<_>, _>>()
+                            .map(json::JsonValue::Array)
+                    } else {
+                        self.unflatten(&data)
+                    },
+                    FlowData::Error(string) => Err(string),
+                    _ => Err("Unknown data".to_string()),
+                };
+
+                return match result {
+                    Ok(data) => FlowData::Json(data),
+                    Err(error) => FlowData::Error(error),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::List(vec![
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Separator"),
+                field: String::from("separator"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Arrays (brackets or keep)"),
+                field: String::from("arrays"),
+            }),
+        ])
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "separator" => NodeUIData::StringData(self.separator.clone()),
+            "arrays" => NodeUIData::StringData(self.arrays.clone()),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("separator", NodeUIData::StringData(string)) => {
+                self.separator = string;
+            }
+            ("arrays", NodeUIData::StringData(string)) => {
+                self.arrays = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("json-unflatten"),
+            attributes: vec![
+                SpecAttribute::String(String::from("separator"), self.separator.clone()),
+                SpecAttribute::String(String::from("arrays"), self.arrays.clone()),
+            ],
+        }
+    }
+}
+
+pub struct JsonUnflattenBuilder {}
+
+impl NodeBuilder for JsonUnflattenBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "json-unflatten" {
+            return Some(Rc::new(RefCell::new(JsonUnflatten {
+                id: id,
+                input: None,
+                separator: String::from(entry["separator"].as_str().unwrap_or(".")),
+                arrays: String::from(entry["arrays"].as_str().unwrap_or("brackets")),
+            })));
+        }
+        None
+    }
+}
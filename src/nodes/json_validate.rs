@@ -0,0 +1,182 @@
+extern crate json;
+
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::json_schema;
+
+pub struct JsonValidate {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub schema_input: Option<Rc<RefCell<Node>>>,
+    pub schema: String,
+    pub on_invalid: String,
+}
+
+impl JsonValidate {
+    // A connected schema input takes precedence over the inline attribute
+    fn load_schema(&self) -> Result<json::JsonValue, String> {
+        match self.schema_input {
+            Some(ref schema_input) => match schema_input.borrow_mut().pull() {
+                FlowData::Json(schema) => Ok(schema),
+                FlowData::Error(string) => Err(string),
+                _ => Err("Incorrect inputs".to_string()),
+            },
+            None => {
+                if self.schema.trim().is_empty() {
+                    return Err("No schema".to_string());
+                }
+                json::parse(self.schema.as_str()).map_err(|error| format!("Invalid schema: {}", error))
+            }
+        }
+    }
+
+    fn validate(&self, schema: &json::JsonValue, document: json::JsonValue) -> FlowData {
+        match self.on_invalid.as_ref() {
+            "error" => match json_schema::validate(schema, &document) {
+                Ok(ref violations) if violations.is_empty() => FlowData::Json(document),
+                Ok(violations) => FlowData::Error(
+                    violations
+                        .iter()
+                        .map(|violation| format!("At '{}': {}", violation.pointer, violation.message))
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                ),
+                Err(error) => FlowData::Error(error),
+            },
+            "report" => match json_schema::validate(schema, &document) {
+                Ok(violations) => FlowData::Json(json::JsonValue::Array(
+                    violations.iter().map(|violation| violation.to_json()).collect(),
+                )),
+                Err(error) => FlowData::Error(error),
+            },
+            "filter" => {
+                if !document.is_array() {
+                    return FlowData::Error("Filtering expects an array of documents".to_string());
+                }
+                let mut valid = vec![];
+                for member in document.members() {
+                    match json_schema::validate(schema, member) {
+                        Ok(ref violations) if violations.is_empty() => valid.push(member.clone()),
+                        Ok(_) => {}
+                        Err(error) => return FlowData::Error(error),
+                    }
+                }
+                FlowData::Json(json::JsonValue::Array(valid))
+            }
+            other => FlowData::Error(format!("Unknown invalid policy '{}'", other)),
+        }
+    }
+}
+
+impl Node for JsonValidate {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        let schema = match self.load_schema() {
+            Ok(schema) => schema,
+            Err(error) => return FlowData::Error(error),
+        };
+
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                return match content {
+                    FlowData::Json(document) => self.validate(&schema, document),
+                    FlowData::Error(string) => FlowData::Error(string),
+                    _ => FlowData::Error("Unknown data".to_string()),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, index: Option<i64>) -> () {
+        match index {
+            Some(1) => {
+                self.input = node;
+            }
+            Some(2) => {
+                self.schema_input = node;
+            }
+            Some(_) => println!("Invalid input index for json-validate"),
+            None => println!("Missing input index for json-validate"),
+        }
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::List(vec![
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Schema"),
+                field: String::from("schema"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("On invalid (error, report or filter)"),
+                field: String::from("on-invalid"),
+            }),
+        ])
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "schema" => NodeUIData::StringData(self.schema.clone()),
+            "on-invalid" => NodeUIData::StringData(self.on_invalid.clone()),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("schema", NodeUIData::StringData(string)) => {
+                self.schema = string;
+            }
+            ("on-invalid", NodeUIData::StringData(string)) => {
+                self.on_invalid = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("json-validate"),
+            attributes: vec![
+                SpecAttribute::String(String::from("schema"), self.schema.clone()),
+                SpecAttribute::String(String::from("on-invalid"), self.on_invalid.clone()),
+            ],
+        }
+    }
+}
+
+pub struct JsonValidateBuilder {}
+
+impl NodeBuilder for JsonValidateBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "json-validate" {
+            return Some(Rc::new(RefCell::new(JsonValidate {
+                id: id,
+                input: None,
+                schema_input: None,
+                schema: String::from(entry["schema"].as_str().unwrap_or("")),
+                on_invalid: String::from(entry["on-invalid"].as_str().unwrap_or("error")),
+            })));
+        }
+        None
+    }
+}
@@ -0,0 +1,264 @@
+extern crate json;
+
+use yaml_rust::Yaml;
+use glob::Pattern;
+use walkdir::{DirEntry, WalkDir};
+use chrono::{DateTime, Utc};
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use BoolFieldData;
+use FlowData;
+
+// Patterns are given as a comma separated list, eg. "*.rs, *.toml"
+fn parse_patterns(text: &str) -> Result<Vec<Pattern>, String> {
+    text.split(',')
+        .map(|pattern| pattern.trim())
+        .filter(|pattern| !pattern.is_empty())
+        .map(|pattern| {
+            Pattern::new(pattern).map_err(|error| format!("Invalid pattern '{}': {}", pattern, error))
+        })
+        .collect()
+}
+
+fn file_type(entry: &DirEntry) -> &'static str {
+    let file_type = entry.file_type();
+    if file_type.is_dir() {
+        "dir"
+    } else if file_type.is_file() {
+        "file"
+    } else if file_type.is_symlink() {
+        "symlink"
+    } else {
+        "other"
+    }
+}
+
+pub struct ListFiles {
+    pub id: i64,
+    pub path: String,
+    pub recursive: bool,
+    pub include: String,
+    pub exclude: String,
+    pub follow_symlinks: bool,
+    pub output: String,
+}
+
+impl ListFiles {
+    fn list(&self) -> Result<Vec<DirEntry>, String> {
+        let include = parse_patterns(self.include.as_str())?;
+        let exclude = parse_patterns(self.exclude.as_str())?;
+
+        let root = if self.path.is_empty() {
+            "."
+        } else {
+            self.path.as_str()
+        };
+
+        let mut walker = WalkDir::new(root)
+            .min_depth(1)
+            .follow_links(self.follow_symlinks)
+            .sort_by(|a, b| a.file_name().cmp(b.file_name()));
+
+        if !self.recursive {
+            walker = walker.max_depth(1);
+        }
+
+        // Patterns match against the path relative to the root. Excluded directories are not
+        // descended into whilst the include patterns only filter what is output.
+        let relative = |entry: &DirEntry| {
+            entry
+                .path()
+                .strip_prefix(root)
+                .unwrap_or(entry.path())
+                .to_path_buf()
+        };
+
+        let mut entries = vec![];
+        for entry in walker
+            .into_iter()
+            .filter_entry(|entry| !exclude.iter().any(|p| p.matches_path(&relative(entry))))
+        {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(error) => return Err(format!("Failed to list files: {}", error)),
+            };
+
+            if include.is_empty() || include.iter().any(|p| p.matches_path(&relative(&entry))) {
+                entries.push(entry);
+            }
+        }
+
+        Ok(entries)
+    }
+
+    fn to_record(&self, entry: &DirEntry) -> Result<json::JsonValue, String> {
+        let metadata = match entry.metadata() {
+            Ok(metadata) => metadata,
+            Err(error) => return Err(format!("Failed to read metadata: {}", error)),
+        };
+
+        let mut object = json::object::Object::new();
+        object.insert(
+            "path",
+            json::JsonValue::String(entry.path().to_string_lossy().into_owned()),
+        );
+        object.insert("size", json::JsonValue::from(metadata.len()));
+        object.insert(
+            "modified",
+            match metadata.modified() {
+                Ok(time) => json::JsonValue::String(DateTime::<Utc>::from(time).to_rfc3339()),
+                Err(_) => json::JsonValue::Null,
+            },
+        );
+        object.insert("type", json::JsonValue::from(file_type(entry)));
+        Ok(json::JsonValue::Object(object))
+    }
+}
+
+impl Node for ListFiles {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        let entries = match self.list() {
+            Ok(entries) => entries,
+            Err(error) => return FlowData::Error(error),
+        };
+
+        match self.output.as_ref() {
+            // Only files are listed so that the paths can be read by file-in. Symbolic links are
+            // resolved to check what they point at.
+            "paths" => FlowData::StringArray(
+                entries
+                    .iter()
+                    .filter(|entry| entry.path().is_file())
+                    .map(|entry| entry.path().to_string_lossy().into_owned())
+                    .collect(),
+            ),
+            _ => {
+                let mut records = json::JsonValue::new_array();
+                for entry in &entries {
+                    match self.to_record(entry) {
+                        Ok(record) => {
+                            let _ = records.push(record);
+                        }
+                        Err(error) => return FlowData::Error(error),
+                    }
+                }
+                FlowData::Json(records)
+            }
+        }
+    }
+
+    fn set_input(&mut self, _node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {}
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::List(vec![
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Path"),
+                field: String::from("path"),
+            }),
+            NodeUI::BoolField(BoolFieldData {
+                label: String::from("Recursive"),
+                field: String::from("recursive"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Include"),
+                field: String::from("include"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Exclude"),
+                field: String::from("exclude"),
+            }),
+            NodeUI::BoolField(BoolFieldData {
+                label: String::from("Follow symlinks"),
+                field: String::from("follow-symlinks"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Output (records or paths)"),
+                field: String::from("output"),
+            }),
+        ])
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "path" => NodeUIData::StringData(self.path.clone()),
+            "recursive" => NodeUIData::BoolData(self.recursive),
+            "include" => NodeUIData::StringData(self.include.clone()),
+            "exclude" => NodeUIData::StringData(self.exclude.clone()),
+            "follow-symlinks" => NodeUIData::BoolData(self.follow_symlinks),
+            "output" => NodeUIData::StringData(self.output.clone()),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("path", NodeUIData::StringData(string)) => {
+                self.path = string;
+            }
+            ("recursive", NodeUIData::BoolData(value)) => {
+                self.recursive = value;
+            }
+            ("include", NodeUIData::StringData(string)) => {
+                self.include = string;
+            }
+            ("exclude", NodeUIData::StringData(string)) => {
+                self.exclude = string;
+            }
+            ("follow-symlinks", NodeUIData::BoolData(value)) => {
+                self.follow_symlinks = value;
+            }
+            ("output", NodeUIData::StringData(string)) => {
+                self.output = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("list-files"),
+            attributes: vec![
+                SpecAttribute::String(String::from("path"), self.path.clone()),
+                SpecAttribute::Bool(String::from("recursive"), self.recursive),
+                SpecAttribute::String(String::from("include"), self.include.clone()),
+                SpecAttribute::String(String::from("exclude"), self.exclude.clone()),
+                SpecAttribute::Bool(String::from("follow-symlinks"), self.follow_symlinks),
+                SpecAttribute::String(String::from("output"), self.output.clone()),
+            ],
+        }
+    }
+}
+
+pub struct ListFilesBuilder {}
+
+impl NodeBuilder for ListFilesBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "list-files" {
+            return Some(Rc::new(RefCell::new(ListFiles {
+                id: id,
+                path: String::from(entry["path"].as_str().unwrap_or(".")),
+                recursive: entry["recursive"].as_bool().unwrap_or(true),
+                include: String::from(entry["include"].as_str().unwrap_or("")),
+                exclude: String::from(entry["exclude"].as_str().unwrap_or("")),
+                follow_symlinks: entry["follow-symlinks"].as_bool().unwrap_or(false),
+                output: String::from(entry["output"].as_str().unwrap_or("records")),
+            })));
+        }
+        None
+    }
+}
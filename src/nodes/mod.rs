@@ -9,6 +9,75 @@ mod json_keys;
 mod json_object;
 mod to_int;
 mod sum;
+mod dates;
+mod records;
+mod json_path;
+mod json_patch;
+mod json_format;
+mod json_schema;
+pub mod patterns;
+mod strings;
+mod ordering;
+mod sequences;
+mod sets;
+mod random;
+mod statistics;
+mod parse_date;
+mod format_date;
+mod date_timezone;
+mod date_diff;
+mod date_truncate;
+mod csv_parse;
+mod csv_stringify;
+mod file_in;
+mod file_out;
+mod list_files;
+mod json_query;
+mod json_values;
+mod json_pluck;
+mod json_omit;
+mod json_merge;
+mod json_merge_patch;
+mod json_patch_apply;
+mod json_diff;
+mod json_flatten;
+mod json_unflatten;
+mod ndjson_parse;
+mod ndjson_stringify;
+mod yaml_parse;
+mod yaml_stringify;
+mod toml_parse;
+mod toml_stringify;
+mod xml_parse;
+mod html_select;
+mod json_validate;
+mod regex_filter;
+mod regex_replace;
+mod regex_extract;
+mod string_trim;
+mod string_prepend;
+mod string_append;
+mod string_case;
+mod string_slugify;
+mod split;
+mod split_each;
+mod join;
+mod sort;
+mod take_drop;
+mod slice;
+mod chunk;
+mod window;
+mod reverse;
+mod set_operation;
+mod unique;
+mod count_distinct;
+mod shuffle;
+mod sample;
+mod sample_fraction;
+mod arithmetic;
+mod aggregate;
+mod group_by;
+mod join_records;
 
 pub use self::standard_in::{StandardIn, StandardInBuilder};
 pub use self::standard_out::{StandardOut, StandardOutBuilder};
@@ -20,3 +89,59 @@ pub use self::json_keys::{JsonKeys, JsonKeysBuilder};
 pub use self::json_object::{JsonObject, JsonObjectBuilder};
 pub use self::to_int::{ToInt, ToIntBuilder};
 pub use self::sum::{Sum, SumBuilder};
+pub use self::parse_date::{ParseDate, ParseDateBuilder};
+pub use self::format_date::{FormatDate, FormatDateBuilder};
+pub use self::date_timezone::{DateTimezone, DateTimezoneBuilder};
+pub use self::date_diff::{DateDiff, DateDiffBuilder};
+pub use self::date_truncate::{DateTruncate, DateTruncateBuilder};
+pub use self::csv_parse::{CsvParse, CsvParseBuilder};
+pub use self::csv_stringify::{CsvStringify, CsvStringifyBuilder};
+pub use self::file_in::{FileIn, FileInBuilder};
+pub use self::file_out::{FileOut, FileOutBuilder};
+pub use self::list_files::{ListFiles, ListFilesBuilder};
+pub use self::json_query::{JsonQuery, JsonQueryBuilder};
+pub use self::json_values::{JsonValues, JsonValuesBuilder};
+pub use self::json_pluck::{JsonPluck, JsonPluckBuilder};
+pub use self::json_omit::{JsonOmit, JsonOmitBuilder};
+pub use self::json_merge::{JsonMerge, JsonMergeBuilder};
+pub use self::json_merge_patch::{JsonMergePatch, JsonMergePatchBuilder};
+pub use self::json_patch_apply::{JsonPatch, JsonPatchBuilder};
+pub use self::json_diff::{JsonDiff, JsonDiffBuilder};
+pub use self::json_flatten::{JsonFlatten, JsonFlattenBuilder};
+pub use self::json_unflatten::{JsonUnflatten, JsonUnflattenBuilder};
+pub use self::ndjson_parse::{NdjsonParse, NdjsonParseBuilder};
+pub use self::ndjson_stringify::{NdjsonStringify, NdjsonStringifyBuilder};
+pub use self::yaml_parse::{YamlParse, YamlParseBuilder};
+pub use self::yaml_stringify::{YamlStringify, YamlStringifyBuilder};
+pub use self::toml_parse::{TomlParse, TomlParseBuilder};
+pub use self::toml_stringify::{TomlStringify, TomlStringifyBuilder};
+pub use self::xml_parse::{XmlParse, XmlParseBuilder};
+pub use self::html_select::{HtmlSelect, HtmlSelectBuilder};
+pub use self::json_validate::{JsonValidate, JsonValidateBuilder};
+pub use self::regex_filter::{RegexFilter, RegexFilterBuilder};
+pub use self::regex_replace::{RegexReplace, RegexReplaceBuilder};
+pub use self::regex_extract::{RegexExtract, RegexExtractBuilder};
+pub use self::string_trim::{StringTrim, StringTrimBuilder};
+pub use self::string_prepend::{StringPrepend, StringPrependBuilder};
+pub use self::string_append::{StringAppend, StringAppendBuilder};
+pub use self::string_case::{StringCase, StringCaseBuilder};
+pub use self::string_slugify::{StringSlugify, StringSlugifyBuilder};
+pub use self::split::{Split, SplitBuilder};
+pub use self::split_each::{SplitEach, SplitEachBuilder};
+pub use self::join::{Join, JoinBuilder};
+pub use self::sort::{Sort, SortBuilder};
+pub use self::take_drop::{TakeDrop, TakeDropBuilder};
+pub use self::slice::{Slice, SliceBuilder};
+pub use self::chunk::{Chunk, ChunkBuilder};
+pub use self::window::{Window, WindowBuilder};
+pub use self::reverse::{Reverse, ReverseBuilder};
+pub use self::set_operation::{SetOperation, SetOperationBuilder};
+pub use self::unique::{Unique, UniqueBuilder};
+pub use self::count_distinct::{CountDistinct, CountDistinctBuilder};
+pub use self::shuffle::{Shuffle, ShuffleBuilder};
+pub use self::sample::{Sample, SampleBuilder};
+pub use self::sample_fraction::{SampleFraction, SampleFractionBuilder};
+pub use self::arithmetic::{Arithmetic, ArithmeticBuilder};
+pub use self::aggregate::{Aggregate, AggregateBuilder};
+pub use self::group_by::{GroupBy, GroupByBuilder};
+pub use self::join_records::{JoinRecords, JoinRecordsBuilder};
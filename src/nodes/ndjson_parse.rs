@@ -0,0 +1,124 @@
+extern crate json;
+
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use BoolFieldData;
+use FlowData;
+
+pub struct NdjsonParse {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub skip_invalid: bool,
+}
+
+impl NdjsonParse {
+    // Blank lines are ignored & line numbers in errors start from 1 to match editors
+    fn parse<'a, I>(&self, lines: I) -> FlowData
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        let mut output = vec![];
+        for (index, line) in lines.enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            match json::parse(line) {
+                Ok(data) => output.push(data),
+                Err(error) => if !self.skip_invalid {
+                    return FlowData::Error(format!(
+                        "Failed to parse json on line {}: {}",
+                        index + 1,
+                        error
+                    ));
+                },
+            }
+        }
+        FlowData::Json(json::JsonValue::Array(output))
+    }
+}
+
+impl Node for NdjsonParse {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                return match content {
+                    FlowData::String(text) => self.parse(text.lines()),
+                    FlowData::StringArray(lines) => {
+                        self.parse(lines.iter().map(|line| line.as_str()))
+                    }
+                    FlowData::Error(string) => FlowData::Error(string),
+                    _ => FlowData::Error("Unknown data".to_string()),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::BoolField(BoolFieldData {
+            label: String::from("Skip invalid lines"),
+            field: String::from("skip-invalid"),
+        })
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        if field == "skip-invalid" {
+            return NodeUIData::BoolData(self.skip_invalid);
+        }
+        NodeUIData::None
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("skip-invalid", NodeUIData::BoolData(value)) => {
+                self.skip_invalid = value;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("ndjson-parse"),
+            attributes: vec![
+                SpecAttribute::Bool(String::from("skip-invalid"), self.skip_invalid),
+            ],
+        }
+    }
+}
+
+pub struct NdjsonParseBuilder {}
+
+impl NodeBuilder for NdjsonParseBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "ndjson-parse" {
+            return Some(Rc::new(RefCell::new(NdjsonParse {
+                id: id,
+                input: None,
+                skip_invalid: entry["skip-invalid"].as_bool().unwrap_or(false),
+            })));
+        }
+        None
+    }
+}
@@ -0,0 +1,72 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use FlowData;
+
+pub struct NdjsonStringify {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+}
+
+impl Node for NdjsonStringify {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                return match content {
+                    FlowData::Json(data) => {
+                        if !data.is_array() {
+                            return FlowData::Error("Expected a json array".to_string());
+                        }
+                        let mut output = String::new();
+                        for member in data.members() {
+                            output.push_str(member.dump().as_str());
+                            output.push('\n');
+                        }
+                        FlowData::String(output)
+                    }
+                    FlowData::Error(string) => FlowData::Error(string),
+                    _ => FlowData::Error("Unknown data".to_string()),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("ndjson-stringify"),
+            attributes: vec![],
+        }
+    }
+}
+
+pub struct NdjsonStringifyBuilder {}
+
+impl NodeBuilder for NdjsonStringifyBuilder {
+    fn build(&self, id: i64, name: &str, _entry: &Yaml) -> Option<NodeRef> {
+        if name == "ndjson-stringify" {
+            return Some(Rc::new(RefCell::new(NdjsonStringify {
+                id: id,
+                input: None,
+            })));
+        }
+        None
+    }
+}
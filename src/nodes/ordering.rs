@@ -0,0 +1,167 @@
+use std::cmp::Ordering;
+
+pub const COMPARISONS: [&'static str; 5] =
+    ["auto", "lexical", "numeric", "natural", "case-insensitive"];
+
+// Ints are kept apart from floats so that large values, which floats cannot hold exactly, still
+// order correctly
+#[derive(Clone, Copy)]
+pub enum Number {
+    Int(i64),
+    Float(f64),
+}
+
+// The value used to order an entry, holding a number when the entry is numeric by type
+pub struct Key {
+    pub text: String,
+    pub number: Option<Number>,
+}
+
+impl Key {
+    pub fn text(text: String) -> Key {
+        Key {
+            text: text,
+            number: None,
+        }
+    }
+
+    pub fn int(text: String, number: i64) -> Key {
+        Key {
+            text: text,
+            number: Some(Number::Int(number)),
+        }
+    }
+
+    pub fn number(text: String, number: f64) -> Key {
+        Key {
+            text: text,
+            number: Some(Number::Float(number)),
+        }
+    }
+}
+
+fn parse_number(text: &str) -> Option<Number> {
+    let text = text.trim();
+    match text.parse::<i64>() {
+        Ok(int) => Some(Number::Int(int)),
+        Err(_) => text.parse::<f64>().ok().map(Number::Float),
+    }
+}
+
+// Compares an Int with a Float exactly by comparing the whole part & then the fraction
+fn compare_int_float(int: i64, float: f64) -> Ordering {
+    if float >= 9_223_372_036_854_775_808.0 {
+        return Ordering::Less;
+    }
+    if float < -9_223_372_036_854_775_808.0 {
+        return Ordering::Greater;
+    }
+    int.cmp(&(float.trunc() as i64))
+        .then_with(|| 0f64.partial_cmp(&float.fract()).unwrap_or(Ordering::Equal))
+}
+
+// A total order over numbers where nan comes after every other number
+fn compare_numbers(a: Number, b: Number) -> Ordering {
+    match (a, b) {
+        (Number::Int(a), Number::Int(b)) => a.cmp(&b),
+        (Number::Float(a), Number::Float(b)) => match (a.is_nan(), b.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => a.total_cmp(&b),
+        },
+        (Number::Int(_), Number::Float(b)) if b.is_nan() => Ordering::Less,
+        (Number::Float(a), Number::Int(_)) if a.is_nan() => Ordering::Greater,
+        (Number::Int(a), Number::Float(b)) => compare_int_float(a, b),
+        (Number::Float(a), Number::Int(b)) => compare_int_float(b, a).reverse(),
+    }
+}
+
+fn digits(chars: &[char], start: usize) -> usize {
+    let mut end = start;
+    while end < chars.len() && chars[end].is_ascii_digit() {
+        end += 1;
+    }
+    end
+}
+
+// Compares runs of digits by their value so that 'file2' comes before 'file10'
+pub fn natural(a: &str, b: &str) -> Ordering {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() && j < b.len() {
+        if a[i].is_ascii_digit() && b[j].is_ascii_digit() {
+            let (a_end, b_end) = (digits(&a, i), digits(&b, j));
+            let a_run: String = a[i..a_end].iter().skip_while(|c| **c == '0').collect();
+            let b_run: String = b[j..b_end].iter().skip_while(|c| **c == '0').collect();
+            let ordering = a_run.len().cmp(&b_run.len()).then_with(|| a_run.cmp(&b_run));
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+            i = a_end;
+            j = b_end;
+        } else {
+            if a[i] != b[j] {
+                return a[i].cmp(&b[j]);
+            }
+            i += 1;
+            j += 1;
+        }
+    }
+
+    (a.len() - i).cmp(&(b.len() - j))
+}
+
+// Numbers come before anything that is not a number, which is then ordered as text
+fn numeric(a: Option<Number>, b: Option<Number>, a_text: &str, b_text: &str) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => compare_numbers(a, b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => a_text.cmp(b_text),
+    }
+}
+
+// 'auto' compares numbers numerically & everything else as text while 'numeric' also reads numbers
+// from text
+pub fn compare(a: &Key, b: &Key, comparison: &str) -> Ordering {
+    match comparison {
+        "lexical" => a.text.cmp(&b.text),
+        "numeric" => {
+            let parse = |key: &Key| key.number.or_else(|| parse_number(&key.text));
+            numeric(parse(a), parse(b), &a.text, &b.text)
+        }
+        "natural" => natural(&a.text, &b.text),
+        "case-insensitive" => a.text.to_lowercase().cmp(&b.text.to_lowercase()),
+        _ => match (a.number, b.number) {
+            (Some(_), _) | (_, Some(_)) => numeric(a.number, b.number, &a.text, &b.text),
+            _ => a.text.cmp(&b.text),
+        },
+    }
+}
+
+// Returns the indices of the keys in sorted order. The sort is stable & when unique is set only the
+// first of each run of equal keys is kept.
+pub fn sorted_indices(
+    keys: &[Key],
+    comparison: &str,
+    descending: bool,
+    unique: bool,
+) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..keys.len()).collect();
+    indices.sort_by(|&a, &b| {
+        let ordering = compare(&keys[a], &keys[b], comparison);
+        if descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+
+    if unique {
+        indices.dedup_by(|a, b| compare(&keys[*a], &keys[*b], comparison) == Ordering::Equal);
+    }
+    indices
+}
@@ -0,0 +1,129 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::dates;
+
+pub struct ParseDate {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub format: String,
+}
+
+impl ParseDate {
+    fn parse_int(&self, value: i64) -> Result<dates::Date, String> {
+        dates::from_epoch(value, self.format.as_str())
+            .ok_or(format!("Unable to convert '{}' to a date", value))
+    }
+}
+
+impl Node for ParseDate {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                return match content {
+                    FlowData::String(text) => match dates::parse(&text, self.format.as_str()) {
+                        Ok(date) => FlowData::DateTime(date),
+                        Err(error) => FlowData::Error(error),
+                    },
+                    FlowData::StringArray(lines) => {
+                        let mut output = vec![];
+                        for line in &lines {
+                            match dates::parse(line, self.format.as_str()) {
+                                Ok(date) => output.push(date),
+                                Err(error) => return FlowData::Error(error),
+                            }
+                        }
+                        return FlowData::DateTimeArray(output);
+                    }
+                    FlowData::Int(int) => match self.parse_int(int) {
+                        Ok(date) => FlowData::DateTime(date),
+                        Err(error) => FlowData::Error(error),
+                    },
+                    FlowData::IntArray(ints) => {
+                        let mut output = vec![];
+                        for int in &ints {
+                            match self.parse_int(*int) {
+                                Ok(date) => output.push(date),
+                                Err(error) => return FlowData::Error(error),
+                            }
+                        }
+                        return FlowData::DateTimeArray(output);
+                    }
+                    FlowData::Error(string) => FlowData::Error(string),
+                    _ => FlowData::Error("Unknown data".to_string()),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::StringField(StringFieldData {
+            label: String::from("Format"),
+            field: String::from("format"),
+        })
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        if field == "format" {
+            return NodeUIData::StringData(self.format.clone());
+        }
+        NodeUIData::None
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("format", NodeUIData::StringData(string)) => {
+                self.format = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("parse-date"),
+            attributes: vec![
+                SpecAttribute::String(String::from("format"), self.format.clone()),
+            ],
+        }
+    }
+}
+
+pub struct ParseDateBuilder {}
+
+impl NodeBuilder for ParseDateBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "parse-date" {
+            return Some(Rc::new(RefCell::new(ParseDate {
+                id: id,
+                input: None,
+                format: String::from(entry["format"].as_str().unwrap_or(dates::RFC3339)),
+            })));
+        }
+        None
+    }
+}
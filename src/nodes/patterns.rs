@@ -0,0 +1,17 @@
+use regex::Regex;
+
+use NodeUI;
+
+pub type Pattern = Result<Regex, String>;
+
+// Patterns are compiled as soon as they are set so that mistakes can be shown while editing
+pub fn compile(pattern: &str) -> Pattern {
+    Regex::new(pattern).map_err(|error| format!("Invalid pattern '{}': {}", pattern, error))
+}
+
+pub fn with_error(mut fields: Vec<NodeUI>, pattern: &Pattern) -> NodeUI {
+    if let Err(ref error) = *pattern {
+        fields.push(NodeUI::Error(error.clone()));
+    }
+    NodeUI::List(fields)
+}
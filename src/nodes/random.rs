@@ -0,0 +1,53 @@
+// A small seeded generator (SplitMix64) so that the same seed gives the same output on every run
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub fn new(seed: i64) -> Random {
+        Random { state: seed as u64 }
+    }
+
+    pub fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        value ^ (value >> 31)
+    }
+
+    // A value from 0 up to but not including the bound
+    pub fn below(&mut self, bound: usize) -> usize {
+        ((self.next() as u128 * bound as u128) >> 64) as usize
+    }
+}
+
+// Returns the indices in a random order using a Fisher-Yates shuffle
+pub fn shuffle(length: usize, seed: i64) -> Vec<usize> {
+    let mut random = Random::new(seed);
+    let mut indices: Vec<usize> = (0..length).collect();
+    for index in (1..length).rev() {
+        let other = random.below(index + 1);
+        indices.swap(index, other);
+    }
+    indices
+}
+
+// Chooses 'count' of the indices with reservoir sampling, which only looks at each entry once, &
+// returns them in their original order
+pub fn sample(length: usize, count: usize, seed: i64) -> Vec<usize> {
+    let mut random = Random::new(seed);
+    let mut reservoir: Vec<usize> = vec![];
+    for index in 0..length {
+        if reservoir.len() < count {
+            reservoir.push(index);
+        } else {
+            let position = random.below(index + 1);
+            if position < count {
+                reservoir[position] = index;
+            }
+        }
+    }
+    reservoir.sort();
+    reservoir
+}
@@ -0,0 +1,122 @@
+extern crate json;
+
+use FlowData;
+use Table;
+
+// Strings are written without quotes and null as empty so that cells read naturally
+pub fn cell_text(value: &json::JsonValue) -> String {
+    match *value {
+        json::JsonValue::Null => String::new(),
+        json::JsonValue::Short(ref short) => short.to_string(),
+        json::JsonValue::String(ref string) => string.clone(),
+        ref other => other.dump(),
+    }
+}
+
+// Converts an array of objects, using every key seen as a column, or an array of arrays
+pub fn json_to_table(value: &json::JsonValue) -> Result<Table, String> {
+    if !value.is_array() {
+        return Err("Expected a json array".to_string());
+    }
+
+    let mut columns: Vec<String> = vec![];
+    let mut rows = vec![];
+
+    if value.members().all(|member| member.is_object()) {
+        for member in value.members() {
+            for (key, _) in member.entries() {
+                if !columns.iter().any(|column| column == key) {
+                    columns.push(key.to_string());
+                }
+            }
+        }
+        for member in value.members() {
+            rows.push(
+                columns
+                    .iter()
+                    .map(|column| cell_text(&member[column.as_str()]))
+                    .collect(),
+            );
+        }
+    } else if value.members().all(|member| member.is_array()) {
+        let width = value.members().map(|member| member.len()).max().unwrap_or(0);
+        columns = (1..width + 1).map(|index| index.to_string()).collect();
+        for member in value.members() {
+            rows.push(
+                (0..width)
+                    .map(|index| cell_text(&member[index]))
+                    .collect(),
+            );
+        }
+    } else {
+        return Err("Expected an array of objects or an array of arrays".to_string());
+    }
+
+    Ok(Table {
+        columns: columns,
+        rows: rows,
+    })
+}
+
+// Each row becomes an object keyed by the column names
+pub fn table_objects(table: Table) -> Vec<json::JsonValue> {
+    let columns = table.columns;
+    table
+        .rows
+        .into_iter()
+        .map(|row| {
+            let mut object = json::object::Object::new();
+            for (column, cell) in columns.iter().zip(row.into_iter()) {
+                object.insert(column.as_str(), json::JsonValue::String(cell));
+            }
+            json::JsonValue::Object(object)
+        })
+        .collect()
+}
+
+// Strings that look like numbers, booleans or null are converted to those types
+pub fn infer_value(text: &str) -> json::JsonValue {
+    match text.trim() {
+        "true" => json::JsonValue::Boolean(true),
+        "false" => json::JsonValue::Boolean(false),
+        "null" => json::JsonValue::Null,
+        trimmed => {
+            if let Ok(int) = trimmed.parse::<i64>() {
+                json::JsonValue::from(int)
+            } else if let Ok(float) = trimmed.parse::<f64>() {
+                if float.is_finite() {
+                    json::JsonValue::from(float)
+                } else {
+                    json::JsonValue::String(text.to_string())
+                }
+            } else {
+                json::JsonValue::String(text.to_string())
+            }
+        }
+    }
+}
+
+// Converts any of the array flow types into a list of json values, preserving their types
+pub fn array_values(data: FlowData, infer: bool) -> Result<Vec<json::JsonValue>, String> {
+    match data {
+        FlowData::StringArray(strings) => Ok(strings
+            .into_iter()
+            .map(|string| if infer {
+                infer_value(string.as_str())
+            } else {
+                json::JsonValue::String(string)
+            })
+            .collect()),
+        FlowData::IntArray(ints) => Ok(ints.into_iter().map(json::JsonValue::from).collect()),
+        FlowData::FloatArray(floats) => Ok(floats.into_iter().map(json::JsonValue::from).collect()),
+        FlowData::BoolArray(bools) => Ok(bools.into_iter().map(json::JsonValue::from).collect()),
+        FlowData::DateTimeArray(dates) => Ok(dates
+            .into_iter()
+            .map(|date| json::JsonValue::String(date.to_rfc3339()))
+            .collect()),
+        FlowData::Json(json::JsonValue::Array(values)) => Ok(values),
+        FlowData::Table(table) => Ok(table_objects(table)),
+        FlowData::Error(string) => Err(string),
+        _ => Err("Expected an array".to_string()),
+    }
+}
@@ -0,0 +1,182 @@
+extern crate json;
+
+use yaml_rust::Yaml;
+use regex::Regex;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+use Table;
+
+use super::patterns;
+
+// Named groups are used as they are, unnamed groups by their number
+fn group_names(regex: &Regex) -> Vec<String> {
+    regex
+        .capture_names()
+        .enumerate()
+        .skip(1)
+        .map(|(index, name)| match name {
+            Some(name) => name.to_string(),
+            None => index.to_string(),
+        })
+        .collect()
+}
+
+pub struct RegexExtract {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub pattern: String,
+    pub regex: patterns::Pattern,
+    pub output: String,
+}
+
+impl RegexExtract {
+    // Lines which do not match are skipped & groups which do not take part in the match are null
+    fn extract(&self, regex: &Regex, lines: Vec<String>) -> FlowData {
+        let names = group_names(regex);
+        let matches = lines.iter().filter_map(|line| regex.captures(line));
+
+        match self.output.as_ref() {
+            "json" => {
+                let mut objects = vec![];
+                for captures in matches {
+                    let mut object = json::object::Object::new();
+                    for (index, name) in names.iter().enumerate() {
+                        let value = match captures.get(index + 1) {
+                            Some(group) => json::JsonValue::from(group.as_str()),
+                            None => json::JsonValue::Null,
+                        };
+                        object.insert(name.as_str(), value);
+                    }
+                    objects.push(json::JsonValue::Object(object));
+                }
+                FlowData::Json(json::JsonValue::Array(objects))
+            }
+            "table" => {
+                let rows = matches
+                    .map(|captures| {
+                        (1..names.len() + 1)
+                            .map(|index| {
+                                captures
+                                    .get(index)
+                                    .map_or(String::new(), |group| group.as_str().to_string())
+                            })
+                            .collect()
+                    })
+                    .collect();
+                FlowData::Table(Table {
+                    columns: names.clone(),
+                    rows: rows,
+                })
+            }
+            other => FlowData::Error(format!("Unknown output type '{}'", other)),
+        }
+    }
+}
+
+impl Node for RegexExtract {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        let regex = match self.regex {
+            Ok(ref regex) => regex.clone(),
+            Err(ref error) => return FlowData::Error(error.clone()),
+        };
+
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                return match content {
+                    FlowData::String(text) => self.extract(&regex, vec![text]),
+                    FlowData::StringArray(lines) => self.extract(&regex, lines),
+                    FlowData::Error(string) => FlowData::Error(string),
+                    _ => FlowData::Error("Unknown data".to_string()),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        patterns::with_error(
+            vec![
+                NodeUI::StringField(StringFieldData {
+                    label: String::from("Pattern"),
+                    field: String::from("pattern"),
+                }),
+                NodeUI::StringField(StringFieldData {
+                    label: String::from("Output (json or table)"),
+                    field: String::from("output"),
+                }),
+            ],
+            &self.regex,
+        )
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "pattern" => NodeUIData::StringData(self.pattern.clone()),
+            "output" => NodeUIData::StringData(self.output.clone()),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("pattern", NodeUIData::StringData(string)) => {
+                self.regex = patterns::compile(string.as_str());
+                self.pattern = string;
+            }
+            ("output", NodeUIData::StringData(string)) => {
+                self.output = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("regex-extract"),
+            attributes: vec![
+                SpecAttribute::String(String::from("pattern"), self.pattern.clone()),
+                SpecAttribute::String(String::from("output"), self.output.clone()),
+            ],
+        }
+    }
+}
+
+pub struct RegexExtractBuilder {}
+
+impl NodeBuilder for RegexExtractBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "regex-extract" {
+            let pattern = entry["pattern"].as_str().unwrap_or("");
+            return Some(Rc::new(RefCell::new(RegexExtract {
+                id: id,
+                input: None,
+                pattern: String::from(pattern),
+                regex: patterns::compile(pattern),
+                output: String::from(entry["output"].as_str().unwrap_or("json")),
+            })));
+        }
+        None
+    }
+}
@@ -0,0 +1,126 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use BoolFieldData;
+use FlowData;
+
+use super::patterns;
+
+pub struct RegexFilter {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub pattern: String,
+    pub regex: patterns::Pattern,
+    pub invert: bool,
+}
+
+impl Node for RegexFilter {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        let regex = match self.regex {
+            Ok(ref regex) => regex,
+            Err(ref error) => return FlowData::Error(error.clone()),
+        };
+
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                return match content {
+                    FlowData::StringArray(lines) => FlowData::StringArray(
+                        lines
+                            .into_iter()
+                            .filter(|line| regex.is_match(line) != self.invert)
+                            .collect(),
+                    ),
+                    FlowData::Error(string) => FlowData::Error(string),
+                    _ => FlowData::Error("Unknown data".to_string()),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        patterns::with_error(
+            vec![
+                NodeUI::StringField(StringFieldData {
+                    label: String::from("Pattern"),
+                    field: String::from("pattern"),
+                }),
+                NodeUI::BoolField(BoolFieldData {
+                    label: String::from("Invert"),
+                    field: String::from("invert"),
+                }),
+            ],
+            &self.regex,
+        )
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "pattern" => NodeUIData::StringData(self.pattern.clone()),
+            "invert" => NodeUIData::BoolData(self.invert),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("pattern", NodeUIData::StringData(string)) => {
+                self.regex = patterns::compile(string.as_str());
+                self.pattern = string;
+            }
+            ("invert", NodeUIData::BoolData(value)) => {
+                self.invert = value;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("regex-filter"),
+            attributes: vec![
+                SpecAttribute::String(String::from("pattern"), self.pattern.clone()),
+                SpecAttribute::Bool(String::from("invert"), self.invert),
+            ],
+        }
+    }
+}
+
+pub struct RegexFilterBuilder {}
+
+impl NodeBuilder for RegexFilterBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "regex-filter" {
+            let pattern = entry["pattern"].as_str().unwrap_or("");
+            return Some(Rc::new(RefCell::new(RegexFilter {
+                id: id,
+                input: None,
+                pattern: String::from(pattern),
+                regex: patterns::compile(pattern),
+                invert: entry["invert"].as_bool().unwrap_or(false),
+            })));
+        }
+        None
+    }
+}
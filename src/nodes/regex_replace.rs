@@ -0,0 +1,145 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use BoolFieldData;
+use FlowData;
+
+use super::patterns;
+
+pub struct RegexReplace {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub pattern: String,
+    pub regex: patterns::Pattern,
+    pub replacement: String,
+    pub all: bool,
+}
+
+impl Node for RegexReplace {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        let regex = match self.regex {
+            Ok(ref regex) => regex.clone(),
+            Err(ref error) => return FlowData::Error(error.clone()),
+        };
+
+        // The replacement can refer to capture groups as $1 or ${name}
+        let replacement = self.replacement.clone();
+        let replacement = replacement.as_str();
+        let all = self.all;
+        let replace = |text: &str| if all {
+            regex.replace_all(text, replacement).into_owned()
+        } else {
+            regex.replace(text, replacement).into_owned()
+        };
+
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                return match content {
+                    FlowData::String(text) => FlowData::String(replace(&text)),
+                    FlowData::StringArray(lines) => {
+                        FlowData::StringArray(lines.iter().map(|line| replace(line)).collect())
+                    }
+                    FlowData::Error(string) => FlowData::Error(string),
+                    _ => FlowData::Error("Unknown data".to_string()),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        patterns::with_error(
+            vec![
+                NodeUI::StringField(StringFieldData {
+                    label: String::from("Pattern"),
+                    field: String::from("pattern"),
+                }),
+                NodeUI::StringField(StringFieldData {
+                    label: String::from("Replacement ($1 or ${name} for groups)"),
+                    field: String::from("replacement"),
+                }),
+                NodeUI::BoolField(BoolFieldData {
+                    label: String::from("Replace all"),
+                    field: String::from("all"),
+                }),
+            ],
+            &self.regex,
+        )
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "pattern" => NodeUIData::StringData(self.pattern.clone()),
+            "replacement" => NodeUIData::StringData(self.replacement.clone()),
+            "all" => NodeUIData::BoolData(self.all),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("pattern", NodeUIData::StringData(string)) => {
+                self.regex = patterns::compile(string.as_str());
+                self.pattern = string;
+            }
+            ("replacement", NodeUIData::StringData(string)) => {
+                self.replacement = string;
+            }
+            ("all", NodeUIData::BoolData(value)) => {
+                self.all = value;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("regex-replace"),
+            attributes: vec![
+                SpecAttribute::String(String::from("pattern"), self.pattern.clone()),
+                SpecAttribute::String(String::from("replacement"), self.replacement.clone()),
+                SpecAttribute::Bool(String::from("all"), self.all),
+            ],
+        }
+    }
+}
+
+pub struct RegexReplaceBuilder {}
+
+impl NodeBuilder for RegexReplaceBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "regex-replace" {
+            let pattern = entry["pattern"].as_str().unwrap_or("");
+            return Some(Rc::new(RefCell::new(RegexReplace {
+                id: id,
+                input: None,
+                pattern: String::from(pattern),
+                regex: patterns::compile(pattern),
+                replacement: String::from(entry["replacement"].as_str().unwrap_or("")),
+                all: entry["all"].as_bool().unwrap_or(true),
+            })));
+        }
+        None
+    }
+}
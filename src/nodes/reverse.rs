@@ -0,0 +1,59 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use FlowData;
+
+use super::sequences;
+
+pub struct Reverse {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+}
+
+impl Node for Reverse {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+                sequences::select(content, |length| Ok((0..length).rev().collect()))
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("reverse"),
+            attributes: vec![],
+        }
+    }
+}
+
+pub struct ReverseBuilder {}
+
+impl NodeBuilder for ReverseBuilder {
+    fn build(&self, id: i64, name: &str, _entry: &Yaml) -> Option<NodeRef> {
+        if name == "reverse" {
+            return Some(Rc::new(RefCell::new(Reverse {
+                id: id,
+                input: None,
+            })));
+        }
+        None
+    }
+}
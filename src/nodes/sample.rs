@@ -0,0 +1,116 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::random;
+use super::sequences;
+
+pub struct Sample {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub count: i64,
+    pub seed: i64,
+}
+
+impl Node for Sample {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        let count = self.count.max(0) as usize;
+        let seed = self.seed;
+
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+                sequences::select(content, |length| Ok(random::sample(length, count, seed)))
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::List(vec![
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Count"),
+                field: String::from("count"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Seed"),
+                field: String::from("seed"),
+            }),
+        ])
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "count" => NodeUIData::StringData(self.count.to_string()),
+            "seed" => NodeUIData::StringData(self.seed.to_string()),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("count", NodeUIData::StringData(string)) => {
+                // An empty field is treated as zero so that it can be retyped
+                if string.trim().is_empty() {
+                    self.count = 0;
+                } else if let Ok(count) = string.trim().parse::<i64>() {
+                    self.count = count.max(0);
+                }
+            }
+            ("seed", NodeUIData::StringData(string)) => {
+                if string.trim().is_empty() {
+                    self.seed = 0;
+                } else if let Ok(seed) = string.trim().parse::<i64>() {
+                    self.seed = seed;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("sample"),
+            attributes: vec![
+                SpecAttribute::Int(String::from("count"), self.count),
+                SpecAttribute::Int(String::from("seed"), self.seed),
+            ],
+        }
+    }
+}
+
+pub struct SampleBuilder {}
+
+impl NodeBuilder for SampleBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "sample" {
+            return Some(Rc::new(RefCell::new(Sample {
+                id: id,
+                input: None,
+                count: entry["count"].as_i64().unwrap_or(10).max(0),
+                seed: entry["seed"].as_i64().unwrap_or(0),
+            })));
+        }
+        None
+    }
+}
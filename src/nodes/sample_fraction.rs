@@ -0,0 +1,128 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::random;
+use super::sequences;
+
+pub struct SampleFraction {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub fraction: f64,
+    pub seed: i64,
+}
+
+impl Node for SampleFraction {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        if !(self.fraction >= 0.0 && self.fraction <= 1.0) {
+            return FlowData::Error("Fraction must be between 0 and 1".to_string());
+        }
+        let fraction = self.fraction;
+        let seed = self.seed;
+
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+                sequences::select(content, |length| {
+                    // The count is rounded so that a fraction always gives the same number of
+                    // entries for a given length
+                    let count = (fraction * length as f64).round() as usize;
+                    Ok(random::sample(length, count, seed))
+                })
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::List(vec![
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Fraction (0 to 1)"),
+                field: String::from("fraction"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Seed"),
+                field: String::from("seed"),
+            }),
+        ])
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "fraction" => NodeUIData::StringData(self.fraction.to_string()),
+            "seed" => NodeUIData::StringData(self.seed.to_string()),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("fraction", NodeUIData::StringData(string)) => {
+                // An empty field is treated as zero so that it can be retyped
+                if string.trim().is_empty() {
+                    self.fraction = 0.0;
+                } else if let Ok(fraction) = string.trim().parse::<f64>() {
+                    self.fraction = fraction;
+                }
+            }
+            ("seed", NodeUIData::StringData(string)) => {
+                if string.trim().is_empty() {
+                    self.seed = 0;
+                } else if let Ok(seed) = string.trim().parse::<i64>() {
+                    self.seed = seed;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("sample-fraction"),
+            attributes: vec![
+                SpecAttribute::Float(String::from("fraction"), self.fraction),
+                SpecAttribute::Int(String::from("seed"), self.seed),
+            ],
+        }
+    }
+}
+
+pub struct SampleFractionBuilder {}
+
+impl NodeBuilder for SampleFractionBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "sample-fraction" {
+            // Whole numbers are read as integers by the yaml parser
+            let fraction = entry["fraction"]
+                .as_f64()
+                .or_else(|| entry["fraction"].as_i64().map(|fraction| fraction as f64));
+            return Some(Rc::new(RefCell::new(SampleFraction {
+                id: id,
+                input: None,
+                fraction: fraction.unwrap_or(0.5),
+                seed: entry["seed"].as_i64().unwrap_or(0),
+            })));
+        }
+        None
+    }
+}
@@ -0,0 +1,128 @@
+extern crate json;
+
+use FlowData;
+use Table;
+
+use super::records;
+
+fn pick<T: Clone>(values: &[T], indices: &[usize]) -> Vec<T> {
+    indices.iter().map(|&index| values[index].clone()).collect()
+}
+
+// Builds an array of the same type as the input from the indices chosen for its length. Tables
+// have their rows chosen.
+pub fn select<F>(content: FlowData, choose: F) -> FlowData
+where
+    F: Fn(usize) -> Result<Vec<usize>, String>,
+{
+    let length = match content {
+        FlowData::StringArray(ref values) => values.len(),
+        FlowData::IntArray(ref values) => values.len(),
+        FlowData::FloatArray(ref values) => values.len(),
+        FlowData::BoolArray(ref values) => values.len(),
+        FlowData::DateTimeArray(ref values) => values.len(),
+        FlowData::Json(json::JsonValue::Array(ref values)) => values.len(),
+        FlowData::Table(ref table) => table.rows.len(),
+        FlowData::Error(string) => return FlowData::Error(string),
+        _ => return FlowData::Error("Expected an array".to_string()),
+    };
+
+    let indices = match choose(length) {
+        Ok(indices) => indices,
+        Err(error) => return FlowData::Error(error),
+    };
+
+    match content {
+        FlowData::StringArray(values) => FlowData::StringArray(pick(&values, &indices)),
+        FlowData::IntArray(values) => FlowData::IntArray(pick(&values, &indices)),
+        FlowData::FloatArray(values) => FlowData::FloatArray(pick(&values, &indices)),
+        FlowData::BoolArray(values) => FlowData::BoolArray(pick(&values, &indices)),
+        FlowData::DateTimeArray(values) => FlowData::DateTimeArray(pick(&values, &indices)),
+        FlowData::Json(json::JsonValue::Array(values)) => {
+            FlowData::Json(json::JsonValue::Array(pick(&values, &indices)))
+        }
+        FlowData::Table(table) => {
+            let rows = pick(&table.rows, &indices);
+            FlowData::Table(Table {
+                columns: table.columns,
+                rows: rows,
+            })
+        }
+        _ => FlowData::Error("Expected an array".to_string()),
+    }
+}
+
+// Groups every 'step' entries into arrays of 'size', including a shorter final group only when
+// 'partial' is set. Entries are converted to json so that any array type can be grouped.
+pub fn groups(content: FlowData, size: i64, step: i64, partial: bool) -> FlowData {
+    if size < 1 || step < 1 {
+        return FlowData::Error("Sizes must be at least 1".to_string());
+    }
+    let (size, step) = (size as usize, step as usize);
+
+    let values = match records::array_values(content, false) {
+        Ok(values) => values,
+        Err(error) => return FlowData::Error(error),
+    };
+
+    let mut output = vec![];
+    let mut start = 0;
+    while start < values.len() {
+        let end = start + size;
+        if end > values.len() && !partial {
+            break;
+        }
+        let end = end.min(values.len());
+        output.push(json::JsonValue::Array(values[start..end].to_vec()));
+        start += step;
+    }
+
+    FlowData::Json(json::JsonValue::Array(output))
+}
+
+// Follows Python's slicing, where negative positions count back from the end & a negative step
+// walks backwards
+pub fn slice(
+    length: usize,
+    start: Option<i64>,
+    end: Option<i64>,
+    step: i64,
+) -> Result<Vec<usize>, String> {
+    if step == 0 {
+        return Err("Slice step cannot be zero".to_string());
+    }
+
+    let length = length as i64;
+    let resolve = |position: i64, lowest: i64, highest: i64| {
+        let position = if position < 0 {
+            position + length
+        } else {
+            position
+        };
+        position.max(lowest).min(highest)
+    };
+
+    let mut indices = vec![];
+    if step > 0 {
+        let mut index = start.map_or(0, |start| resolve(start, 0, length));
+        let end = end.map_or(length, |end| resolve(end, 0, length));
+        while index < end {
+            indices.push(index as usize);
+            index = match index.checked_add(step) {
+                Some(index) => index,
+                None => break,
+            };
+        }
+    } else {
+        let mut index = start.map_or(length - 1, |start| resolve(start, -1, length - 1));
+        let end = end.map_or(-1, |end| resolve(end, -1, length - 1));
+        while index > end {
+            indices.push(index as usize);
+            index = match index.checked_add(step) {
+                Some(index) => index,
+                None => break,
+            };
+        }
+    }
+    Ok(indices)
+}
@@ -0,0 +1,93 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::sets;
+
+// Union, intersection & difference share a node, differing only in how the inputs are combined
+pub struct SetOperation {
+    pub id: i64,
+    pub operation: String,
+    pub inputs: Vec<Option<Rc<RefCell<Node>>>>,
+    pub key: String,
+}
+
+impl Node for SetOperation {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match sets::pull_all(&self.inputs) {
+            Ok(ref contents) if contents.len() < 2 => {
+                FlowData::Error("Insufficient inputs".to_string())
+            }
+            Ok(contents) => sets::combine(contents, self.key.as_str(), self.operation.as_str()),
+            Err(error) => FlowData::Error(error),
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, index: Option<i64>) -> () {
+        sets::set_port(&mut self.inputs, node, index, self.operation.as_str());
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::StringField(StringFieldData {
+            label: String::from("Key (json path or column)"),
+            field: String::from("key"),
+        })
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        if field == "key" {
+            return NodeUIData::StringData(self.key.clone());
+        }
+        NodeUIData::None
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("key", NodeUIData::StringData(string)) => {
+                self.key = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: self.operation.clone(),
+            attributes: vec![
+                SpecAttribute::String(String::from("key"), self.key.clone()),
+            ],
+        }
+    }
+}
+
+pub struct SetOperationBuilder {}
+
+impl NodeBuilder for SetOperationBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "union" || name == "intersection" || name == "difference" {
+            return Some(Rc::new(RefCell::new(SetOperation {
+                id: id,
+                operation: String::from(name),
+                inputs: vec![],
+                key: String::from(entry["key"].as_str().unwrap_or("")),
+            })));
+        }
+        None
+    }
+}
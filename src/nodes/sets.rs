@@ -0,0 +1,184 @@
+extern crate json;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+use std::collections::HashSet;
+
+use Node;
+use FlowData;
+use Table;
+
+use super::json_path;
+use super::sequences;
+
+// Stores an input at its port, growing the list of inputs as needed. Ports are numbered from 1.
+pub fn set_port(
+    inputs: &mut Vec<Option<Rc<RefCell<Node>>>>,
+    node: Option<Rc<RefCell<Node>>>,
+    index: Option<i64>,
+    name: &str,
+) {
+    match index {
+        Some(index) if index >= 1 => {
+            let port = (index - 1) as usize;
+            if inputs.len() <= port {
+                inputs.resize(port + 1, None);
+            }
+            inputs[port] = node;
+        }
+        Some(_) => println!("Invalid input index for {}", name),
+        None => println!("Missing input index for {}", name),
+    }
+}
+
+// Pulls from each connected port in order, skipping any that are not connected
+pub fn pull_all(inputs: &Vec<Option<Rc<RefCell<Node>>>>) -> Result<Vec<FlowData>, String> {
+    let mut contents = vec![];
+    for input in inputs {
+        if let Some(ref input) = *input {
+            match input.borrow_mut().pull() {
+                FlowData::Error(string) => return Err(string),
+                content => contents.push(content),
+            }
+        }
+    }
+    Ok(contents)
+}
+
+// The text used to decide whether two entries are the same. Json members can be compared by the
+// value at a key path & Table rows by a single column.
+pub fn identities(content: &FlowData, key: &str) -> Result<Vec<String>, String> {
+    match *content {
+        FlowData::StringArray(ref values) => Ok(values.clone()),
+        FlowData::IntArray(ref values) => {
+            Ok(values.iter().map(|value| value.to_string()).collect())
+        }
+        FlowData::FloatArray(ref values) => {
+            Ok(values.iter().map(|value| value.to_string()).collect())
+        }
+        FlowData::BoolArray(ref values) => {
+            Ok(values.iter().map(|value| value.to_string()).collect())
+        }
+        FlowData::DateTimeArray(ref values) => {
+            Ok(values.iter().map(|value| value.to_rfc3339()).collect())
+        }
+        FlowData::Json(json::JsonValue::Array(ref values)) => {
+            let segments = json_path::parse(key)?;
+            Ok(values
+                .iter()
+                .map(|value| match json_path::query(value, &segments).first() {
+                    Some(matched) => matched.dump(),
+                    None => json::JsonValue::Null.dump(),
+                })
+                .collect())
+        }
+        FlowData::Table(ref table) => {
+            if key.is_empty() {
+                return Ok(table
+                    .rows
+                    .iter()
+                    .map(|row| {
+                        let cells = row.iter().map(|cell| cell.as_str().into()).collect();
+                        json::JsonValue::Array(cells).dump()
+                    })
+                    .collect());
+            }
+            match table.columns.iter().position(|column| column == key) {
+                // Short rows, as csv-parse gives for ragged input, have empty cells
+                Some(column) => Ok(table
+                    .rows
+                    .iter()
+                    .map(|row| row.get(column).cloned().unwrap_or(String::new()))
+                    .collect()),
+                None => Err(format!("No column named '{}'", key)),
+            }
+        }
+        _ => Err("Expected an array".to_string()),
+    }
+}
+
+// Joins arrays of the same type end to end. Tables must have the same columns.
+fn concat(contents: Vec<FlowData>) -> Result<FlowData, String> {
+    let mismatch = || "Inputs must be arrays of the same type".to_string();
+    let mut contents = contents.into_iter();
+    let mut output = match contents.next() {
+        Some(content) => content,
+        None => return Err("Insufficient inputs".to_string()),
+    };
+
+    for content in contents {
+        match (&mut output, content) {
+            (&mut FlowData::StringArray(ref mut all), FlowData::StringArray(values)) => {
+                all.extend(values)
+            }
+            (&mut FlowData::IntArray(ref mut all), FlowData::IntArray(values)) => {
+                all.extend(values)
+            }
+            (&mut FlowData::FloatArray(ref mut all), FlowData::FloatArray(values)) => {
+                all.extend(values)
+            }
+            (&mut FlowData::BoolArray(ref mut all), FlowData::BoolArray(values)) => {
+                all.extend(values)
+            }
+            (&mut FlowData::DateTimeArray(ref mut all), FlowData::DateTimeArray(values)) => {
+                all.extend(values)
+            }
+            (
+                &mut FlowData::Json(json::JsonValue::Array(ref mut all)),
+                FlowData::Json(json::JsonValue::Array(values)),
+            ) => all.extend(values),
+            (&mut FlowData::Table(ref mut all), FlowData::Table(Table { columns, rows })) => {
+                if all.columns != columns {
+                    return Err("Tables must have the same columns".to_string());
+                }
+                all.rows.extend(rows)
+            }
+            _ => return Err(mismatch()),
+        }
+    }
+    Ok(output)
+}
+
+// Combines the inputs keeping the first of each entry in the order they are first seen:
+// 'union' keeps entries from any input, 'intersection' those of the first input found in every
+// other input & 'difference' those of the first input found in no other input
+pub fn combine(contents: Vec<FlowData>, key: &str, operation: &str) -> FlowData {
+    let mut sets: Vec<Vec<String>> = vec![];
+    for content in &contents {
+        match identities(content, key) {
+            Ok(identities) => sets.push(identities),
+            Err(error) => return FlowData::Error(error),
+        }
+    }
+
+    if sets.is_empty() {
+        return FlowData::Error("Insufficient inputs".to_string());
+    }
+
+    let others: Vec<HashSet<&String>> = sets[1..].iter().map(|set| set.iter().collect()).collect();
+    let first_length = sets[0].len();
+    let all: Vec<&String> = sets.iter().flat_map(|set| set.iter()).collect();
+
+    let mut seen = HashSet::new();
+    let mut indices = vec![];
+    for (index, identity) in all.iter().enumerate() {
+        let keep = match operation {
+            "union" => true,
+            "intersection" => {
+                index < first_length && others.iter().all(|set| set.contains(identity))
+            }
+            "difference" => {
+                index < first_length && !others.iter().any(|set| set.contains(identity))
+            }
+            _ => false,
+        };
+        if keep && seen.insert(*identity) {
+            indices.push(index);
+        }
+    }
+
+    match concat(contents) {
+        Ok(content) => sequences::select(content, |_| Ok(indices.clone())),
+        Err(error) => FlowData::Error(error),
+    }
+}
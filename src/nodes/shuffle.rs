@@ -0,0 +1,96 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::random;
+use super::sequences;
+
+pub struct Shuffle {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub seed: i64,
+}
+
+impl Node for Shuffle {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        let seed = self.seed;
+
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+                sequences::select(content, |length| Ok(random::shuffle(length, seed)))
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::StringField(StringFieldData {
+            label: String::from("Seed"),
+            field: String::from("seed"),
+        })
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        if field == "seed" {
+            return NodeUIData::StringData(self.seed.to_string());
+        }
+        NodeUIData::None
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("seed", NodeUIData::StringData(string)) => {
+                // An empty field is treated as zero so that it can be retyped
+                if string.trim().is_empty() {
+                    self.seed = 0;
+                } else if let Ok(seed) = string.trim().parse::<i64>() {
+                    self.seed = seed;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("shuffle"),
+            attributes: vec![SpecAttribute::Int(String::from("seed"), self.seed)],
+        }
+    }
+}
+
+pub struct ShuffleBuilder {}
+
+impl NodeBuilder for ShuffleBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "shuffle" {
+            return Some(Rc::new(RefCell::new(Shuffle {
+                id: id,
+                input: None,
+                seed: entry["seed"].as_i64().unwrap_or(0),
+            })));
+        }
+        None
+    }
+}
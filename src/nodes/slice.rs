@@ -0,0 +1,136 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::sequences;
+
+fn optional_text(value: Option<i64>) -> String {
+    value.map_or(String::new(), |value| value.to_string())
+}
+
+pub struct Slice {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub start: Option<i64>,
+    pub end: Option<i64>,
+    pub step: i64,
+}
+
+impl Node for Slice {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        let (start, end, step) = (self.start, self.end, self.step);
+
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+                sequences::select(content, |length| sequences::slice(length, start, end, step))
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::List(vec![
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Start"),
+                field: String::from("start"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("End"),
+                field: String::from("end"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Step"),
+                field: String::from("step"),
+            }),
+        ])
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "start" => NodeUIData::StringData(optional_text(self.start)),
+            "end" => NodeUIData::StringData(optional_text(self.end)),
+            "step" => NodeUIData::StringData(self.step.to_string()),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        // Empty start & end fields mean the start & end of the array
+        match (field.as_ref(), data) {
+            ("start", NodeUIData::StringData(string)) => {
+                if string.trim().is_empty() {
+                    self.start = None;
+                } else if let Ok(start) = string.trim().parse::<i64>() {
+                    self.start = Some(start);
+                }
+            }
+            ("end", NodeUIData::StringData(string)) => {
+                if string.trim().is_empty() {
+                    self.end = None;
+                } else if let Ok(end) = string.trim().parse::<i64>() {
+                    self.end = Some(end);
+                }
+            }
+            ("step", NodeUIData::StringData(string)) => {
+                if let Ok(step) = string.trim().parse::<i64>() {
+                    self.step = step;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        let mut attributes = vec![];
+        if let Some(start) = self.start {
+            attributes.push(SpecAttribute::Int(String::from("start"), start));
+        }
+        if let Some(end) = self.end {
+            attributes.push(SpecAttribute::Int(String::from("end"), end));
+        }
+        attributes.push(SpecAttribute::Int(String::from("step"), self.step));
+
+        Spec {
+            id: self.id,
+            type_: String::from("slice"),
+            attributes: attributes,
+        }
+    }
+}
+
+pub struct SliceBuilder {}
+
+impl NodeBuilder for SliceBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "slice" {
+            return Some(Rc::new(RefCell::new(Slice {
+                id: id,
+                input: None,
+                start: entry["start"].as_i64(),
+                end: entry["end"].as_i64(),
+                step: entry["step"].as_i64().unwrap_or(1),
+            })));
+        }
+        None
+    }
+}
@@ -0,0 +1,245 @@
+extern crate json;
+
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use BoolFieldData;
+use FlowData;
+use Table;
+
+use super::json_path;
+use super::ordering::{self, Key};
+use super::records;
+
+fn json_key(value: &json::JsonValue) -> Key {
+    if !value.is_number() {
+        return Key::text(records::cell_text(value));
+    }
+    match (value.as_i64(), value.as_f64()) {
+        (Some(int), _) => Key::int(value.dump(), int),
+        (None, Some(number)) => Key::number(value.dump(), number),
+        (None, None) => Key::text(value.dump()),
+    }
+}
+
+fn pick<T: Clone>(values: &[T], indices: &[usize]) -> Vec<T> {
+    indices.iter().map(|&index| values[index].clone()).collect()
+}
+
+pub struct Sort {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub order: String,
+    pub compare: String,
+    pub key: String,
+    pub unique: bool,
+}
+
+impl Sort {
+    fn indices(&self, keys: &[Key]) -> Vec<usize> {
+        ordering::sorted_indices(
+            keys,
+            self.compare.as_str(),
+            self.order == "descending",
+            self.unique,
+        )
+    }
+
+    // Members are ordered by the first value matching the key path, or by themselves without one
+    fn sort_json(&self, data: json::JsonValue) -> FlowData {
+        if !data.is_array() {
+            return FlowData::Error("Expected a json array to sort".to_string());
+        }
+
+        let segments = match json_path::parse(self.key.as_str()) {
+            Ok(segments) => segments,
+            Err(error) => return FlowData::Error(error),
+        };
+
+        let members: Vec<json::JsonValue> = data.members().cloned().collect();
+        let keys: Vec<Key> = members
+            .iter()
+            .map(|member| match json_path::query(member, &segments).first() {
+                Some(value) => json_key(value),
+                None => Key::text(String::new()),
+            })
+            .collect();
+
+        FlowData::Json(json::JsonValue::Array(pick(&members, &self.indices(&keys))))
+    }
+
+    // Rows are ordered by the key column, or by the first column without one
+    fn sort_table(&self, table: Table) -> FlowData {
+        let column = if self.key.is_empty() {
+            0
+        } else {
+            match table.columns.iter().position(|column| *column == self.key) {
+                Some(column) => column,
+                None => return FlowData::Error(format!("No column named '{}'", self.key)),
+            }
+        };
+
+        let keys: Vec<Key> = table
+            .rows
+            .iter()
+            .map(|row| Key::text(row.get(column).cloned().unwrap_or(String::new())))
+            .collect();
+
+        let rows = pick(&table.rows, &self.indices(&keys));
+        FlowData::Table(Table {
+            columns: table.columns,
+            rows: rows,
+        })
+    }
+}
+
+impl Node for Sort {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.order.as_ref() {
+            "ascending" | "descending" => {}
+            other => return FlowData::Error(format!("Unknown order '{}'", other)),
+        }
+        if !ordering::COMPARISONS.contains(&self.compare.as_str()) {
+            return FlowData::Error(format!("Unknown comparison '{}'", self.compare));
+        }
+
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                return match content {
+                    FlowData::StringArray(strings) => {
+                        let keys: Vec<Key> = strings.iter().cloned().map(Key::text).collect();
+                        FlowData::StringArray(pick(&strings, &self.indices(&keys)))
+                    }
+                    FlowData::IntArray(ints) => {
+                        let keys: Vec<Key> = ints
+                            .iter()
+                            .map(|int| Key::int(int.to_string(), *int))
+                            .collect();
+                        FlowData::IntArray(pick(&ints, &self.indices(&keys)))
+                    }
+                    FlowData::FloatArray(floats) => {
+                        let keys: Vec<Key> = floats
+                            .iter()
+                            .map(|float| Key::number(float.to_string(), *float))
+                            .collect();
+                        FlowData::FloatArray(pick(&floats, &self.indices(&keys)))
+                    }
+                    FlowData::DateTimeArray(dates) => {
+                        let keys: Vec<Key> = dates
+                            .iter()
+                            .map(|date| {
+                                Key::int(date.to_rfc3339(), date.timestamp_millis())
+                            })
+                            .collect();
+                        FlowData::DateTimeArray(pick(&dates, &self.indices(&keys)))
+                    }
+                    FlowData::Json(data) => self.sort_json(data),
+                    FlowData::Table(table) => self.sort_table(table),
+                    FlowData::Error(string) => FlowData::Error(string),
+                    _ => FlowData::Error("Unknown data".to_string()),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::List(vec![
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Order (ascending or descending)"),
+                field: String::from("order"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Compare (auto, lexical, numeric, natural, case-insensitive)"),
+                field: String::from("compare"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Key (json path or column)"),
+                field: String::from("key"),
+            }),
+            NodeUI::BoolField(BoolFieldData {
+                label: String::from("Unique"),
+                field: String::from("unique"),
+            }),
+        ])
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "order" => NodeUIData::StringData(self.order.clone()),
+            "compare" => NodeUIData::StringData(self.compare.clone()),
+            "key" => NodeUIData::StringData(self.key.clone()),
+            "unique" => NodeUIData::BoolData(self.unique),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("order", NodeUIData::StringData(string)) => {
+                self.order = string;
+            }
+            ("compare", NodeUIData::StringData(string)) => {
+                self.compare = string;
+            }
+            ("key", NodeUIData::StringData(string)) => {
+                self.key = string;
+            }
+            ("unique", NodeUIData::BoolData(value)) => {
+                self.unique = value;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("sort"),
+            attributes: vec![
+                SpecAttribute::String(String::from("order"), self.order.clone()),
+                SpecAttribute::String(String::from("compare"), self.compare.clone()),
+                SpecAttribute::String(String::from("key"), self.key.clone()),
+                SpecAttribute::Bool(String::from("unique"), self.unique),
+            ],
+        }
+    }
+}
+
+pub struct SortBuilder {}
+
+impl NodeBuilder for SortBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "sort" {
+            return Some(Rc::new(RefCell::new(Sort {
+                id: id,
+                input: None,
+                order: String::from(entry["order"].as_str().unwrap_or("ascending")),
+                compare: String::from(entry["compare"].as_str().unwrap_or("auto")),
+                key: String::from(entry["key"].as_str().unwrap_or("")),
+                unique: entry["unique"].as_bool().unwrap_or(false),
+            })));
+        }
+        None
+    }
+}
@@ -0,0 +1,222 @@
+extern crate json;
+
+use yaml_rust::Yaml;
+use regex::Regex;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use BoolFieldData;
+use FlowData;
+use Table;
+
+use super::patterns;
+use super::strings;
+
+pub struct SplitEach {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub delimiter: String,
+    pub regex: bool,
+    pub max_splits: i64,
+    pub keep_empty: bool,
+    pub splitter: patterns::Pattern,
+    pub output: String,
+    pub columns: String,
+}
+
+impl SplitEach {
+    fn split_all(&self, splitter: &Regex, lines: Vec<String>) -> FlowData {
+        let rows: Vec<Vec<String>> = lines
+            .iter()
+            .map(|line| strings::split(splitter, line, self.max_splits, self.keep_empty))
+            .collect();
+
+        match self.output.as_ref() {
+            "json" => {
+                let arrays = rows.into_iter()
+                    .map(|row| {
+                        json::JsonValue::Array(row.into_iter().map(json::JsonValue::from).collect())
+                    })
+                    .collect();
+                FlowData::Json(json::JsonValue::Array(arrays))
+            }
+            "table" => {
+                // Named columns come first with any extra columns numbered after them, and short
+                // rows are padded so that every row has a cell for each column
+                let mut columns: Vec<String> = self.columns
+                    .split(',')
+                    .map(|column| column.trim().to_string())
+                    .filter(|column| !column.is_empty())
+                    .collect();
+                let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+                for index in columns.len()..width {
+                    columns.push((index + 1).to_string());
+                }
+
+                let rows = rows.into_iter()
+                    .map(|mut row| {
+                        row.resize(columns.len(), String::new());
+                        row
+                    })
+                    .collect();
+                FlowData::Table(Table {
+                    columns: columns,
+                    rows: rows,
+                })
+            }
+            other => FlowData::Error(format!("Unknown output type '{}'", other)),
+        }
+    }
+}
+
+impl Node for SplitEach {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        let splitter = match self.splitter {
+            Ok(ref splitter) => splitter.clone(),
+            Err(ref error) => return FlowData::Error(error.clone()),
+        };
+
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                return match content {
+                    FlowData::StringArray(lines) => self.split_all(&splitter, lines),
+                    FlowData::Error(string) => FlowData::Error(string),
+                    _ => FlowData::Error("Unknown data".to_string()),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        patterns::with_error(
+            vec![
+                NodeUI::StringField(StringFieldData {
+                    label: String::from("Delimiter"),
+                    field: String::from("delimiter"),
+                }),
+                NodeUI::BoolField(BoolFieldData {
+                    label: String::from("Regex delimiter"),
+                    field: String::from("regex"),
+                }),
+                NodeUI::StringField(StringFieldData {
+                    label: String::from("Max splits (0 for no limit)"),
+                    field: String::from("max-splits"),
+                }),
+                NodeUI::BoolField(BoolFieldData {
+                    label: String::from("Keep empty"),
+                    field: String::from("keep-empty"),
+                }),
+                NodeUI::StringField(StringFieldData {
+                    label: String::from("Output (json or table)"),
+                    field: String::from("output"),
+                }),
+                NodeUI::StringField(StringFieldData {
+                    label: String::from("Columns (comma separated)"),
+                    field: String::from("columns"),
+                }),
+            ],
+            &self.splitter,
+        )
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "delimiter" => NodeUIData::StringData(self.delimiter.clone()),
+            "regex" => NodeUIData::BoolData(self.regex),
+            "max-splits" => NodeUIData::StringData(self.max_splits.to_string()),
+            "keep-empty" => NodeUIData::BoolData(self.keep_empty),
+            "output" => NodeUIData::StringData(self.output.clone()),
+            "columns" => NodeUIData::StringData(self.columns.clone()),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("delimiter", NodeUIData::StringData(string)) => {
+                self.delimiter = string;
+                self.splitter = strings::delimiter(&self.delimiter, self.regex);
+            }
+            ("regex", NodeUIData::BoolData(value)) => {
+                self.regex = value;
+                self.splitter = strings::delimiter(&self.delimiter, self.regex);
+            }
+            ("max-splits", NodeUIData::StringData(string)) => {
+                // An empty field is treated as no limit so that it can be retyped
+                if string.trim().is_empty() {
+                    self.max_splits = 0;
+                } else if let Ok(max_splits) = string.trim().parse::<i64>() {
+                    self.max_splits = max_splits.max(0);
+                }
+            }
+            ("keep-empty", NodeUIData::BoolData(value)) => {
+                self.keep_empty = value;
+            }
+            ("output", NodeUIData::StringData(string)) => {
+                self.output = string;
+            }
+            ("columns", NodeUIData::StringData(string)) => {
+                self.columns = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("split-each"),
+            attributes: vec![
+                SpecAttribute::String(String::from("delimiter"), self.delimiter.clone()),
+                SpecAttribute::Bool(String::from("regex"), self.regex),
+                SpecAttribute::Int(String::from("max-splits"), self.max_splits),
+                SpecAttribute::Bool(String::from("keep-empty"), self.keep_empty),
+                SpecAttribute::String(String::from("output"), self.output.clone()),
+                SpecAttribute::String(String::from("columns"), self.columns.clone()),
+            ],
+        }
+    }
+}
+
+pub struct SplitEachBuilder {}
+
+impl NodeBuilder for SplitEachBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "split-each" {
+            let delimiter = entry["delimiter"].as_str().unwrap_or(",");
+            let regex = entry["regex"].as_bool().unwrap_or(false);
+            return Some(Rc::new(RefCell::new(SplitEach {
+                id: id,
+                input: None,
+                delimiter: String::from(delimiter),
+                regex: regex,
+                max_splits: entry["max-splits"].as_i64().unwrap_or(0).max(0),
+                keep_empty: entry["keep-empty"].as_bool().unwrap_or(true),
+                splitter: strings::delimiter(delimiter, regex),
+                output: String::from(entry["output"].as_str().unwrap_or("json")),
+                columns: String::from(entry["columns"].as_str().unwrap_or("")),
+            })));
+        }
+        None
+    }
+}
@@ -0,0 +1,257 @@
+extern crate json;
+
+use FlowData;
+
+use super::json_path;
+use super::records;
+
+pub const FUNCTIONS: [&'static str; 10] = [
+    "count",
+    "sum",
+    "mean",
+    "median",
+    "min",
+    "max",
+    "stddev",
+    "percentile",
+    "histogram",
+    "summary",
+];
+
+pub enum Numbers {
+    Ints(Vec<i64>),
+    Floats(Vec<f64>),
+}
+
+impl Numbers {
+    pub fn len(&self) -> usize {
+        match *self {
+            Numbers::Ints(ref values) => values.len(),
+            Numbers::Floats(ref values) => values.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn floats(&self) -> Vec<f64> {
+        match *self {
+            Numbers::Ints(ref values) => values.iter().map(|value| *value as f64).collect(),
+            Numbers::Floats(ref values) => values.clone(),
+        }
+    }
+}
+
+// Collects the numbers from json values, skipping anything that is not a number. They are kept as
+// Ints when every one of them is whole.
+pub fn from_json(values: &[&json::JsonValue]) -> Numbers {
+    let numbers: Vec<&json::JsonValue> =
+        values.iter().cloned().filter(|value| value.is_number()).collect();
+    let ints: Vec<Option<i64>> = numbers.iter().map(|value| value.as_i64()).collect();
+    if ints.iter().all(|int| int.is_some()) {
+        return Numbers::Ints(ints.into_iter().map(|int| int.unwrap_or(0)).collect());
+    }
+    Numbers::Floats(numbers.iter().filter_map(|value| value.as_f64()).collect())
+}
+
+// Reads numbers from arrays, from text that looks like numbers, from the values at a key path in
+// Json members or from a Table column, which defaults to the first
+pub fn numbers(content: FlowData, key: &str) -> Result<Numbers, String> {
+    match content {
+        FlowData::IntArray(values) => Ok(Numbers::Ints(values)),
+        FlowData::FloatArray(values) => Ok(Numbers::Floats(values)),
+        FlowData::StringArray(strings) => {
+            let values: Vec<json::JsonValue> =
+                strings.iter().map(|string| records::infer_value(string)).collect();
+            Ok(from_json(&values.iter().collect::<Vec<_>>()))
+        }
+        FlowData::Json(json::JsonValue::Array(members)) => {
+            let segments = json_path::parse(key)?;
+            let values: Vec<&json::JsonValue> = members
+                .iter()
+                .filter_map(|member| json_path::query(member, &segments).first().cloned())
+                .collect();
+            Ok(from_json(&values))
+        }
+        FlowData::Table(table) => {
+            let column = if key.is_empty() {
+                0
+            } else {
+                match table.columns.iter().position(|column| column == key) {
+                    Some(column) => column,
+                    None => return Err(format!("No column named '{}'", key)),
+                }
+            };
+            let values: Vec<json::JsonValue> = table
+                .rows
+                .iter()
+                .filter_map(|row| row.get(column))
+                .map(|cell| records::infer_value(cell))
+                .collect();
+            Ok(from_json(&values.iter().collect::<Vec<_>>()))
+        }
+        FlowData::Error(string) => Err(string),
+        _ => Err("Unknown data".to_string()),
+    }
+}
+
+fn sorted(numbers: &Numbers) -> Vec<f64> {
+    let mut values = numbers.floats();
+    values.sort_by(|a, b| a.total_cmp(b));
+    values
+}
+
+// Interpolates between the two closest values, as with numpy's default percentile
+fn percentile_of(sorted: &[f64], percentile: f64) -> f64 {
+    let rank = percentile / 100.0 * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+fn mean(numbers: &Numbers) -> f64 {
+    numbers.floats().iter().sum::<f64>() / numbers.len() as f64
+}
+
+// The population standard deviation
+fn stddev(numbers: &Numbers) -> f64 {
+    let mean = mean(numbers);
+    let squares: f64 = numbers
+        .floats()
+        .iter()
+        .map(|value| (value - mean) * (value - mean))
+        .sum();
+    (squares / numbers.len() as f64).sqrt()
+}
+
+fn sum(numbers: &Numbers) -> Result<FlowData, String> {
+    match *numbers {
+        Numbers::Ints(ref values) => values
+            .iter()
+            .fold(Some(0i64), |total, value| total.and_then(|total| total.checked_add(*value)))
+            .map(FlowData::Int)
+            .ok_or_else(|| "Overflow".to_string()),
+        Numbers::Floats(ref values) => Ok(FlowData::Float(values.iter().sum())),
+    }
+}
+
+fn extreme(numbers: &Numbers, maximum: bool) -> FlowData {
+    match *numbers {
+        Numbers::Ints(ref values) => {
+            let value = if maximum {
+                values.iter().max()
+            } else {
+                values.iter().min()
+            };
+            FlowData::Int(*value.unwrap_or(&0))
+        }
+        Numbers::Floats(ref values) => {
+            let first = values.first().cloned().unwrap_or(0.0);
+            FlowData::Float(values.iter().fold(first, |extreme, value| if maximum {
+                extreme.max(*value)
+            } else {
+                extreme.min(*value)
+            }))
+        }
+    }
+}
+
+// Splits the range of values into equal width bins, with the last bin including the maximum. There
+// are never more bins than values & when every value is the same there is a single bin.
+fn histogram(numbers: &Numbers, bins: i64) -> Result<json::JsonValue, String> {
+    if bins < 1 {
+        return Err("Bins must be at least 1".to_string());
+    }
+    let values = sorted(numbers);
+    let (low, high) = (values[0], values[values.len() - 1]);
+    let bins = if high > low {
+        (bins as usize).min(values.len())
+    } else {
+        1
+    };
+    let width = (high - low) / bins as f64;
+
+    let mut counts = vec![0; bins];
+    for value in values {
+        let bin = if width > 0.0 {
+            (((value - low) / width) as usize).min(bins - 1)
+        } else {
+            0
+        };
+        counts[bin] += 1;
+    }
+
+    Ok(json::JsonValue::Array(
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(index, count)| {
+                let mut object = json::JsonValue::new_object();
+                object["start"] = json::JsonValue::from(low + width * index as f64);
+                object["end"] = json::JsonValue::from(low + width * (index + 1) as f64);
+                object["count"] = json::JsonValue::from(count);
+                object
+            })
+            .collect(),
+    ))
+}
+
+pub fn to_json(content: FlowData) -> json::JsonValue {
+    match content {
+        FlowData::Int(value) => json::JsonValue::from(value),
+        FlowData::Float(value) => json::JsonValue::from(value),
+        FlowData::Json(value) => value,
+        _ => json::JsonValue::Null,
+    }
+}
+
+fn summary(numbers: &Numbers) -> Result<json::JsonValue, String> {
+    let mut object = json::JsonValue::new_object();
+    for function in &["count", "sum", "mean", "median", "min", "max", "stddev"] {
+        object[*function] = if numbers.is_empty() && *function != "count" && *function != "sum" {
+            json::JsonValue::Null
+        } else {
+            to_json(aggregate(numbers, function, 50.0, 1)?)
+        };
+    }
+    Ok(object)
+}
+
+// Count, sum & summary accept no values while the rest need at least one. Count, sum, min & max
+// keep Ints as Ints.
+pub fn aggregate(
+    numbers: &Numbers,
+    function: &str,
+    percentile: f64,
+    bins: i64,
+) -> Result<FlowData, String> {
+    match function {
+        "count" => return Ok(FlowData::Int(numbers.len() as i64)),
+        "sum" => return sum(numbers),
+        "summary" => return summary(numbers).map(FlowData::Json),
+        _ => {}
+    }
+
+    if !FUNCTIONS.contains(&function) {
+        return Err(format!("Unknown function '{}'", function));
+    }
+    if numbers.is_empty() {
+        return Err("No numbers to aggregate".to_string());
+    }
+
+    match function {
+        "mean" => Ok(FlowData::Float(mean(numbers))),
+        "median" => Ok(FlowData::Float(percentile_of(&sorted(numbers), 50.0))),
+        "min" => Ok(extreme(numbers, false)),
+        "max" => Ok(extreme(numbers, true)),
+        "stddev" => Ok(FlowData::Float(stddev(numbers))),
+        "percentile" => {
+            if !(percentile >= 0.0 && percentile <= 100.0) {
+                return Err("Percentile must be between 0 and 100".to_string());
+            }
+            Ok(FlowData::Float(percentile_of(&sorted(numbers), percentile)))
+        }
+        _ => histogram(numbers, bins).map(FlowData::Json),
+    }
+}
@@ -0,0 +1,90 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::strings;
+
+pub struct StringAppend {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub value: String,
+}
+
+impl Node for StringAppend {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+                strings::map(content, |text| format!("{}{}", text, self.value))
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::StringField(StringFieldData {
+            label: String::from("Value"),
+            field: String::from("value"),
+        })
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        if field == "value" {
+            return NodeUIData::StringData(self.value.clone());
+        }
+        NodeUIData::None
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("value", NodeUIData::StringData(string)) => {
+                self.value = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("string-append"),
+            attributes: vec![
+                SpecAttribute::String(String::from("value"), self.value.clone()),
+            ],
+        }
+    }
+}
+
+pub struct StringAppendBuilder {}
+
+impl NodeBuilder for StringAppendBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "string-append" {
+            return Some(Rc::new(RefCell::new(StringAppend {
+                id: id,
+                input: None,
+                value: String::from(entry["value"].as_str().unwrap_or("")),
+            })));
+        }
+        None
+    }
+}
@@ -0,0 +1,94 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::strings;
+
+pub struct StringCase {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub case: String,
+}
+
+impl Node for StringCase {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        if !strings::CASES.contains(&self.case.as_str()) {
+            return FlowData::Error(format!("Unknown case '{}'", self.case));
+        }
+
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+                strings::map(content, |text| strings::convert_case(text, self.case.as_str()))
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::StringField(StringFieldData {
+            label: String::from("Case (upper, lower, title, camel, pascal, snake or kebab)"),
+            field: String::from("case"),
+        })
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        if field == "case" {
+            return NodeUIData::StringData(self.case.clone());
+        }
+        NodeUIData::None
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("case", NodeUIData::StringData(string)) => {
+                self.case = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("string-case"),
+            attributes: vec![
+                SpecAttribute::String(String::from("case"), self.case.clone()),
+            ],
+        }
+    }
+}
+
+pub struct StringCaseBuilder {}
+
+impl NodeBuilder for StringCaseBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "string-case" {
+            return Some(Rc::new(RefCell::new(StringCase {
+                id: id,
+                input: None,
+                case: String::from(entry["case"].as_str().unwrap_or("lower")),
+            })));
+        }
+        None
+    }
+}
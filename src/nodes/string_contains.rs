@@ -1,4 +1,3 @@
-
 use yaml_rust::Yaml;
 
 use std::rc::Rc;
@@ -12,12 +11,92 @@ use NodeBuilder;
 use NodeUI;
 use NodeUIData;
 use StringFieldData;
+use BoolFieldData;
 use FlowData;
 
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+// Whether the text at start..end is not joined on to other word characters
+fn is_whole_word(line: &str, start: usize, end: usize) -> bool {
+    let before = line[..start].chars().next_back();
+    let after = line[end..].chars().next();
+    !before.map_or(false, is_word_char) && !after.map_or(false, is_word_char)
+}
+
 pub struct StringContains {
     pub id: i64,
     pub input: Option<Rc<RefCell<Node>>>,
     pub value: String,
+    pub terms: String,
+    pub position: String,
+    pub case_insensitive: bool,
+    pub whole_word: bool,
+    pub invert: bool,
+}
+
+impl StringContains {
+    fn matches_term(&self, line: &str, term: &str) -> bool {
+        match self.position.as_ref() {
+            "start" => {
+                line.starts_with(term) && (!self.whole_word || is_whole_word(line, 0, term.len()))
+            }
+            "end" => {
+                let start = line.len().saturating_sub(term.len());
+                line.ends_with(term) && (!self.whole_word || is_whole_word(line, start, line.len()))
+            }
+            _ => if self.whole_word {
+                line.match_indices(term)
+                    .any(|(start, _)| is_whole_word(line, start, start + term.len()))
+            } else {
+                line.contains(term)
+            },
+        }
+    }
+
+    fn matches(&self, line: &str, terms: &[String]) -> bool {
+        let line = if self.case_insensitive {
+            line.to_lowercase()
+        } else {
+            line.to_string()
+        };
+
+        let found = if self.terms == "all" {
+            terms.iter().all(|term| self.matches_term(&line, term))
+        } else {
+            terms.iter().any(|term| self.matches_term(&line, term))
+        };
+
+        found != self.invert
+    }
+
+    // With 'any' or 'all' the value holds several comma separated terms
+    fn split_terms(&self) -> Result<Vec<String>, String> {
+        let terms: Vec<String> = match self.terms.as_ref() {
+            "single" => vec![self.value.clone()],
+            "any" | "all" => self.value
+                .split(',')
+                .map(|term| term.trim().to_string())
+                .filter(|term| !term.is_empty())
+                .collect(),
+            other => return Err(format!("Unknown terms option '{}'", other)),
+        };
+
+        match self.position.as_ref() {
+            "anywhere" | "start" | "end" => {}
+            other => return Err(format!("Unknown position '{}'", other)),
+        }
+
+        Ok(terms
+            .into_iter()
+            .map(|term| if self.case_insensitive {
+                term.to_lowercase()
+            } else {
+                term
+            })
+            .collect())
+    }
 }
 
 impl Node for StringContains {
@@ -25,6 +104,11 @@ impl Node for StringContains {
         self.id
     }
     fn pull(&mut self) -> FlowData {
+        let terms = match self.split_terms() {
+            Ok(terms) => terms,
+            Err(error) => return FlowData::Error(error),
+        };
+
         match self.input {
             None => return FlowData::Error("No input".to_string()),
             Some(ref mut input) => {
@@ -34,7 +118,7 @@ impl Node for StringContains {
                     FlowData::StringArray(lines) => {
                         let mut output = vec![];
                         for i in &lines {
-                            if i.contains(self.value.as_str()) {
+                            if self.matches(i, &terms) {
                                 output.push(i.to_string());
                             }
                         }
@@ -52,17 +136,44 @@ impl Node for StringContains {
     }
 
     fn get_ui(&self) -> NodeUI {
-        NodeUI::StringField(StringFieldData {
-            label: String::from("Value"),
-            field: String::from("value"),
-        })
+        NodeUI::List(vec![
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Value"),
+                field: String::from("value"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Terms (single, any or all)"),
+                field: String::from("terms"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Position (anywhere, start or end)"),
+                field: String::from("position"),
+            }),
+            NodeUI::BoolField(BoolFieldData {
+                label: String::from("Case insensitive"),
+                field: String::from("case-insensitive"),
+            }),
+            NodeUI::BoolField(BoolFieldData {
+                label: String::from("Whole word"),
+                field: String::from("whole-word"),
+            }),
+            NodeUI::BoolField(BoolFieldData {
+                label: String::from("Invert"),
+                field: String::from("invert"),
+            }),
+        ])
     }
 
     fn get_value(&self, field: &String) -> NodeUIData {
-        if field == "value" {
-            return NodeUIData::StringData(self.value.clone());
+        match field.as_ref() {
+            "value" => NodeUIData::StringData(self.value.clone()),
+            "terms" => NodeUIData::StringData(self.terms.clone()),
+            "position" => NodeUIData::StringData(self.position.clone()),
+            "case-insensitive" => NodeUIData::BoolData(self.case_insensitive),
+            "whole-word" => NodeUIData::BoolData(self.whole_word),
+            "invert" => NodeUIData::BoolData(self.invert),
+            _ => NodeUIData::None,
         }
-        NodeUIData::None
     }
 
     fn set_value(&mut self, field: &String, data: NodeUIData) {
@@ -70,6 +181,21 @@ impl Node for StringContains {
             ("value", NodeUIData::StringData(string)) => {
                 self.value = string;
             }
+            ("terms", NodeUIData::StringData(string)) => {
+                self.terms = string;
+            }
+            ("position", NodeUIData::StringData(string)) => {
+                self.position = string;
+            }
+            ("case-insensitive", NodeUIData::BoolData(value)) => {
+                self.case_insensitive = value;
+            }
+            ("whole-word", NodeUIData::BoolData(value)) => {
+                self.whole_word = value;
+            }
+            ("invert", NodeUIData::BoolData(value)) => {
+                self.invert = value;
+            }
             _ => {}
         }
     }
@@ -80,6 +206,11 @@ impl Node for StringContains {
             type_: String::from("string-contains"),
             attributes: vec![
                 SpecAttribute::String(String::from("value"), self.value.clone()),
+                SpecAttribute::String(String::from("terms"), self.terms.clone()),
+                SpecAttribute::String(String::from("position"), self.position.clone()),
+                SpecAttribute::Bool(String::from("case-insensitive"), self.case_insensitive),
+                SpecAttribute::Bool(String::from("whole-word"), self.whole_word),
+                SpecAttribute::Bool(String::from("invert"), self.invert),
             ],
         }
     }
@@ -89,12 +220,17 @@ pub struct StringContainsBuilder {}
 
 impl NodeBuilder for StringContainsBuilder {
     fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
-        if name == "lines" {
+        if name == "string-contains" {
             if let Some(value) = entry["value"].as_str() {
                 return Some(Rc::new(RefCell::new(StringContains {
                     id: id,
                     input: None,
                     value: String::from(value),
+                    terms: String::from(entry["terms"].as_str().unwrap_or("single")),
+                    position: String::from(entry["position"].as_str().unwrap_or("anywhere")),
+                    case_insensitive: entry["case-insensitive"].as_bool().unwrap_or(false),
+                    whole_word: entry["whole-word"].as_bool().unwrap_or(false),
+                    invert: entry["invert"].as_bool().unwrap_or(false),
                 })));
             } else {
                 println!("No 'value' for string contains node");
@@ -0,0 +1,90 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::strings;
+
+pub struct StringSlugify {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub separator: String,
+}
+
+impl Node for StringSlugify {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+                strings::map(content, |text| strings::slugify(text, self.separator.as_str()))
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::StringField(StringFieldData {
+            label: String::from("Separator"),
+            field: String::from("separator"),
+        })
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        if field == "separator" {
+            return NodeUIData::StringData(self.separator.clone());
+        }
+        NodeUIData::None
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("separator", NodeUIData::StringData(string)) => {
+                self.separator = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("string-slugify"),
+            attributes: vec![
+                SpecAttribute::String(String::from("separator"), self.separator.clone()),
+            ],
+        }
+    }
+}
+
+pub struct StringSlugifyBuilder {}
+
+impl NodeBuilder for StringSlugifyBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "string-slugify" {
+            return Some(Rc::new(RefCell::new(StringSlugify {
+                id: id,
+                input: None,
+                separator: String::from(entry["separator"].as_str().unwrap_or("-")),
+            })));
+        }
+        None
+    }
+}
@@ -0,0 +1,126 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::strings;
+
+pub struct StringTrim {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub side: String,
+    pub characters: String,
+}
+
+impl StringTrim {
+    // Without any characters given, whitespace is trimmed
+    fn trim(&self, text: &str) -> String {
+        let characters: Vec<char> = self.characters.chars().collect();
+        let trimmed = |c: char| if characters.is_empty() {
+            c.is_whitespace()
+        } else {
+            characters.contains(&c)
+        };
+
+        match self.side.as_ref() {
+            "start" => text.trim_start_matches(trimmed).to_string(),
+            "end" => text.trim_end_matches(trimmed).to_string(),
+            _ => text.trim_matches(trimmed).to_string(),
+        }
+    }
+}
+
+impl Node for StringTrim {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.side.as_ref() {
+            "both" | "start" | "end" => {}
+            other => return FlowData::Error(format!("Unknown side '{}'", other)),
+        }
+
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+                strings::map(content, |text| self.trim(text))
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::List(vec![
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Side (both, start or end)"),
+                field: String::from("side"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Characters (empty for whitespace)"),
+                field: String::from("characters"),
+            }),
+        ])
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "side" => NodeUIData::StringData(self.side.clone()),
+            "characters" => NodeUIData::StringData(self.characters.clone()),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("side", NodeUIData::StringData(string)) => {
+                self.side = string;
+            }
+            ("characters", NodeUIData::StringData(string)) => {
+                self.characters = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("string-trim"),
+            attributes: vec![
+                SpecAttribute::String(String::from("side"), self.side.clone()),
+                SpecAttribute::String(String::from("characters"), self.characters.clone()),
+            ],
+        }
+    }
+}
+
+pub struct StringTrimBuilder {}
+
+impl NodeBuilder for StringTrimBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "string-trim" {
+            return Some(Rc::new(RefCell::new(StringTrim {
+                id: id,
+                input: None,
+                side: String::from(entry["side"].as_str().unwrap_or("both")),
+                characters: String::from(entry["characters"].as_str().unwrap_or("")),
+            })));
+        }
+        None
+    }
+}
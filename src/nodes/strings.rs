@@ -0,0 +1,191 @@
+use regex::{self, Regex};
+
+use FlowData;
+
+use super::patterns;
+
+pub const CASES: [&'static str; 7] = ["upper", "lower", "title", "camel", "pascal", "snake", "kebab"];
+
+// Applies the transform to a String or to each entry of a StringArray
+pub fn map<F>(content: FlowData, transform: F) -> FlowData
+where
+    F: Fn(&str) -> String,
+{
+    match content {
+        FlowData::String(string) => FlowData::String(transform(&string)),
+        FlowData::StringArray(strings) => {
+            FlowData::StringArray(strings.iter().map(|string| transform(string)).collect())
+        }
+        FlowData::Error(string) => FlowData::Error(string),
+        _ => FlowData::Error("Unknown data".to_string()),
+    }
+}
+
+// Apostrophes are dropped rather than splitting words, so that "don't" gives 'dont'
+fn is_apostrophe(c: char) -> bool {
+    c == '\'' || c == '\u{2019}'
+}
+
+// Splits on anything that is not a letter or digit & where the case changes, so that
+// 'parseHTTPResponse' & 'parse_http_response' both give 'parse', 'HTTP' & 'Response'
+fn words(text: &str) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut words = vec![];
+    let mut current = String::new();
+
+    for (index, &c) in chars.iter().enumerate() {
+        if is_apostrophe(c) {
+            continue;
+        }
+
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(current);
+                current = String::new();
+            }
+            continue;
+        }
+
+        if !current.is_empty() {
+            let previous = chars[index - 1];
+            let next = chars.get(index + 1).cloned();
+            let starts_word = c.is_uppercase()
+                && (previous.is_lowercase() || previous.is_numeric()
+                    || (previous.is_uppercase() && next.map_or(false, |n| n.is_lowercase())));
+            if starts_word {
+                words.push(current);
+                current = String::new();
+            }
+        }
+        current.push(c);
+    }
+
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+fn capitalise(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => {
+            let mut output: String = first.to_uppercase().collect();
+            output.push_str(&chars.as_str().to_lowercase());
+            output
+        }
+        None => String::new(),
+    }
+}
+
+// Title case keeps the original spacing & punctuation, only changing the case of each word
+fn title(text: &str) -> String {
+    let mut output = String::new();
+    let mut word = String::new();
+    for c in text.chars() {
+        if c.is_alphanumeric() || is_apostrophe(c) {
+            word.push(c);
+        } else {
+            output.push_str(&capitalise(&word));
+            word.clear();
+            output.push(c);
+        }
+    }
+    output.push_str(&capitalise(&word));
+    output
+}
+
+pub fn convert_case(text: &str, case: &str) -> String {
+    match case {
+        "upper" => text.to_uppercase(),
+        "lower" => text.to_lowercase(),
+        "title" => title(text),
+        "camel" => words(text)
+            .iter()
+            .enumerate()
+            .map(|(index, word)| if index == 0 {
+                word.to_lowercase()
+            } else {
+                capitalise(word)
+            })
+            .collect(),
+        "pascal" => words(text).iter().map(|word| capitalise(word)).collect(),
+        "snake" => join_lowercase(words(text), "_"),
+        "kebab" => join_lowercase(words(text), "-"),
+        _ => text.to_string(),
+    }
+}
+
+fn join_lowercase(words: Vec<String>, separator: &str) -> String {
+    words
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+// Lowercases & joins each run of letters & digits with the separator, dropping everything else
+pub fn slugify(text: &str, separator: &str) -> String {
+    let lower: String = text.to_lowercase().chars().filter(|c| !is_apostrophe(*c)).collect();
+    let parts: Vec<&str> = lower
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect();
+    parts.join(separator)
+}
+
+// Allows delimiters such as '\t' & '\n' to be typed into a text field
+pub fn unescape(text: &str) -> String {
+    let mut output = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            output.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => output.push('\n'),
+            Some('r') => output.push('\r'),
+            Some('t') => output.push('\t'),
+            Some('\\') => output.push('\\'),
+            Some(other) => {
+                output.push('\\');
+                output.push(other);
+            }
+            None => output.push('\\'),
+        }
+    }
+    output
+}
+
+// Literal delimiters are escaped so that both kinds can be split with a regex. A literal newline
+// also matches '\r\n' so that files with Windows line endings split cleanly.
+pub fn delimiter(delimiter: &str, regex: bool) -> patterns::Pattern {
+    if delimiter.is_empty() {
+        return Err("Empty delimiter".to_string());
+    }
+
+    if regex {
+        return patterns::compile(delimiter);
+    }
+
+    match unescape(delimiter).as_ref() {
+        "\n" => patterns::compile("\r?\n"),
+        literal => patterns::compile(&regex::escape(literal)),
+    }
+}
+
+// A max of zero splits means no limit
+pub fn split(delimiter: &Regex, text: &str, max_splits: i64, keep_empty: bool) -> Vec<String> {
+    let parts: Vec<&str> = if max_splits > 0 {
+        delimiter.splitn(text, max_splits as usize + 1).collect()
+    } else {
+        delimiter.split(text).collect()
+    };
+
+    parts
+        .into_iter()
+        .filter(|part| keep_empty || !part.is_empty())
+        .map(String::from)
+        .collect()
+}
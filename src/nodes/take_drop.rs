@@ -0,0 +1,129 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::sequences;
+
+// TakeDrop & drop share a node as they are opposites, one keeping the entries the other removes
+pub struct TakeDrop {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub operation: String,
+    pub count: i64,
+    pub from: String,
+}
+
+impl Node for TakeDrop {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        let count = self.count.max(0) as usize;
+        let drop = self.operation == "drop";
+        let from_end = match self.from.as_ref() {
+            "start" => false,
+            "end" => true,
+            other => return FlowData::Error(format!("Unknown position '{}'", other)),
+        };
+
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+                sequences::select(content, |length| {
+                    let count = count.min(length);
+                    let indices = match (drop, from_end) {
+                        (false, false) => 0..count,
+                        (false, true) => length - count..length,
+                        (true, false) => count..length,
+                        (true, true) => 0..length - count,
+                    };
+                    Ok(indices.collect())
+                })
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::List(vec![
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Count"),
+                field: String::from("count"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("From (start or end)"),
+                field: String::from("from"),
+            }),
+        ])
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "count" => NodeUIData::StringData(self.count.to_string()),
+            "from" => NodeUIData::StringData(self.from.clone()),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("count", NodeUIData::StringData(string)) => {
+                // An empty field is treated as zero so that it can be retyped
+                if string.trim().is_empty() {
+                    self.count = 0;
+                } else if let Ok(count) = string.trim().parse::<i64>() {
+                    self.count = count.max(0);
+                }
+            }
+            ("from", NodeUIData::StringData(string)) => {
+                self.from = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: self.operation.clone(),
+            attributes: vec![
+                SpecAttribute::Int(String::from("count"), self.count),
+                SpecAttribute::String(String::from("from"), self.from.clone()),
+            ],
+        }
+    }
+}
+
+pub struct TakeDropBuilder {}
+
+impl NodeBuilder for TakeDropBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "take" || name == "drop" {
+            let count = if name == "take" { 10 } else { 1 };
+            return Some(Rc::new(RefCell::new(TakeDrop {
+                id: id,
+                input: None,
+                operation: String::from(name),
+                count: entry["count"].as_i64().unwrap_or(count).max(0),
+                from: String::from(entry["from"].as_str().unwrap_or("start")),
+            })));
+        }
+        None
+    }
+}
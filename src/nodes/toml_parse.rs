@@ -0,0 +1,99 @@
+extern crate json;
+
+use yaml_rust::Yaml;
+use toml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use FlowData;
+
+// Toml dates have no json equivalent so they are kept as their string representation. Infinite &
+// nan floats cannot be represented in json so they are reported rather than silently dropped.
+fn to_json(value: &toml::Value) -> Result<json::JsonValue, String> {
+    match *value {
+        toml::Value::String(ref string) => Ok(json::JsonValue::String(string.clone())),
+        toml::Value::Integer(int) => Ok(json::JsonValue::from(int)),
+        toml::Value::Float(float) => if float.is_finite() {
+            Ok(json::JsonValue::from(float))
+        } else {
+            Err(format!("Cannot represent '{}' in json", float))
+        },
+        toml::Value::Boolean(boolean) => Ok(json::JsonValue::Boolean(boolean)),
+        toml::Value::Datetime(ref datetime) => Ok(json::JsonValue::String(datetime.to_string())),
+        toml::Value::Array(ref array) => array
+            .iter()
+            .map(to_json)
+            .collect::<Result<Vec<_>, _>>()
+            .map(json::JsonValue::Array),
+        toml::Value::Table(ref table) => {
+            let mut object = json::object::Object::new();
+            for (key, child) in table.iter() {
+                object.insert(key.as_str(), to_json(child)?);
+            }
+            Ok(json::JsonValue::Object(object))
+        }
+    }
+}
+
+pub struct TomlParse {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+}
+
+impl Node for TomlParse {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                return match content {
+                    FlowData::String(text) => match toml::from_str::<toml::Value>(&text) {
+                        Ok(value) => match to_json(&value) {
+                            Ok(data) => FlowData::Json(data),
+                            Err(error) => FlowData::Error(error),
+                        },
+                        Err(error) => FlowData::Error(format!("Failed to parse toml: {}", error)),
+                    },
+                    FlowData::Error(string) => FlowData::Error(string),
+                    _ => FlowData::Error("Unknown data".to_string()),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("toml-parse"),
+            attributes: vec![],
+        }
+    }
+}
+
+pub struct TomlParseBuilder {}
+
+impl NodeBuilder for TomlParseBuilder {
+    fn build(&self, id: i64, name: &str, _entry: &Yaml) -> Option<NodeRef> {
+        if name == "toml-parse" {
+            return Some(Rc::new(RefCell::new(TomlParse {
+                id: id,
+                input: None,
+            })));
+        }
+        None
+    }
+}
@@ -0,0 +1,103 @@
+extern crate json;
+
+use yaml_rust::Yaml;
+use toml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use FlowData;
+
+// Toml has no null so it is reported rather than silently dropping keys
+fn to_toml(value: &json::JsonValue) -> Result<toml::Value, String> {
+    match *value {
+        json::JsonValue::Null => Err("Toml cannot represent null values".to_string()),
+        json::JsonValue::Boolean(boolean) => Ok(toml::Value::Boolean(boolean)),
+        json::JsonValue::Number(_) => match value.as_i64() {
+            Some(int) => Ok(toml::Value::Integer(int)),
+            None => Ok(toml::Value::Float(value.as_f64().unwrap_or(0.0))),
+        },
+        json::JsonValue::Short(ref short) => Ok(toml::Value::String(short.to_string())),
+        json::JsonValue::String(ref string) => Ok(toml::Value::String(string.clone())),
+        json::JsonValue::Array(ref array) => array
+            .iter()
+            .map(to_toml)
+            .collect::<Result<Vec<_>, _>>()
+            .map(toml::Value::Array),
+        json::JsonValue::Object(ref object) => {
+            let mut table = toml::value::Table::new();
+            for (key, child) in object.iter() {
+                table.insert(key.to_string(), to_toml(child)?);
+            }
+            Ok(toml::Value::Table(table))
+        }
+    }
+}
+
+pub struct TomlStringify {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+}
+
+impl Node for TomlStringify {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                return match content {
+                    FlowData::Json(data) => {
+                        if !data.is_object() {
+                            return FlowData::Error("Toml documents must be objects".to_string());
+                        }
+                        match to_toml(&data).and_then(|value| {
+                            toml::to_string(&value).map_err(|error| error.to_string())
+                        }) {
+                            Ok(string) => FlowData::String(string),
+                            Err(error) => {
+                                FlowData::Error(format!("Failed to convert to toml: {}", error))
+                            }
+                        }
+                    }
+                    FlowData::Error(string) => FlowData::Error(string),
+                    _ => FlowData::Error("Unknown data".to_string()),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("toml-stringify"),
+            attributes: vec![],
+        }
+    }
+}
+
+pub struct TomlStringifyBuilder {}
+
+impl NodeBuilder for TomlStringifyBuilder {
+    fn build(&self, id: i64, name: &str, _entry: &Yaml) -> Option<NodeRef> {
+        if name == "toml-stringify" {
+            return Some(Rc::new(RefCell::new(TomlStringify {
+                id: id,
+                input: None,
+            })));
+        }
+        None
+    }
+}
@@ -0,0 +1,94 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::sets;
+
+pub struct Unique {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub key: String,
+}
+
+impl Node for Unique {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                return match content {
+                    FlowData::Error(string) => FlowData::Error(string),
+                    content => sets::combine(vec![content], self.key.as_str(), "union"),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::StringField(StringFieldData {
+            label: String::from("Key (json path or column)"),
+            field: String::from("key"),
+        })
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        if field == "key" {
+            return NodeUIData::StringData(self.key.clone());
+        }
+        NodeUIData::None
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("key", NodeUIData::StringData(string)) => {
+                self.key = string;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("unique"),
+            attributes: vec![
+                SpecAttribute::String(String::from("key"), self.key.clone()),
+            ],
+        }
+    }
+}
+
+pub struct UniqueBuilder {}
+
+impl NodeBuilder for UniqueBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "unique" {
+            return Some(Rc::new(RefCell::new(Unique {
+                id: id,
+                input: None,
+                key: String::from(entry["key"].as_str().unwrap_or("")),
+            })));
+        }
+        None
+    }
+}
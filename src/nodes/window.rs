@@ -0,0 +1,107 @@
+use yaml_rust::Yaml;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use StringFieldData;
+use FlowData;
+
+use super::sequences;
+
+pub struct Window {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub size: i64,
+    pub step: i64,
+}
+
+impl Node for Window {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+                sequences::groups(content, self.size, self.step, false)
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::List(vec![
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Size"),
+                field: String::from("size"),
+            }),
+            NodeUI::StringField(StringFieldData {
+                label: String::from("Step"),
+                field: String::from("step"),
+            }),
+        ])
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        match field.as_ref() {
+            "size" => NodeUIData::StringData(self.size.to_string()),
+            "step" => NodeUIData::StringData(self.step.to_string()),
+            _ => NodeUIData::None,
+        }
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("size", NodeUIData::StringData(string)) => {
+                if let Ok(size) = string.trim().parse::<i64>() {
+                    self.size = size;
+                }
+            }
+            ("step", NodeUIData::StringData(string)) => {
+                if let Ok(step) = string.trim().parse::<i64>() {
+                    self.step = step;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("window"),
+            attributes: vec![
+                SpecAttribute::Int(String::from("size"), self.size),
+                SpecAttribute::Int(String::from("step"), self.step),
+            ],
+        }
+    }
+}
+
+pub struct WindowBuilder {}
+
+impl NodeBuilder for WindowBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "window" {
+            return Some(Rc::new(RefCell::new(Window {
+                id: id,
+                input: None,
+                size: entry["size"].as_i64().unwrap_or(2),
+                step: entry["step"].as_i64().unwrap_or(1),
+            })));
+        }
+        None
+    }
+}
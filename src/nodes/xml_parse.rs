@@ -0,0 +1,93 @@
+extern crate json;
+
+use yaml_rust::Yaml;
+use roxmltree;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use FlowData;
+
+// Each element becomes an object holding its name, attributes, direct text & child elements
+fn to_json(node: roxmltree::Node) -> json::JsonValue {
+    let mut attributes = json::object::Object::new();
+    for attribute in node.attributes() {
+        attributes.insert(attribute.name(), json::JsonValue::from(attribute.value()));
+    }
+
+    let mut text = String::new();
+    let mut children = vec![];
+    for child in node.children() {
+        if child.is_element() {
+            children.push(to_json(child));
+        } else if child.is_text() {
+            text.push_str(child.text().unwrap_or(""));
+        }
+    }
+
+    let mut object = json::object::Object::new();
+    object.insert("name", json::JsonValue::from(node.tag_name().name()));
+    object.insert("attributes", json::JsonValue::Object(attributes));
+    object.insert("text", json::JsonValue::from(text.trim()));
+    object.insert("children", json::JsonValue::Array(children));
+    json::JsonValue::Object(object)
+}
+
+pub struct XmlParse {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+}
+
+impl Node for XmlParse {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                return match content {
+                    FlowData::String(text) => match roxmltree::Document::parse(&text) {
+                        Ok(document) => FlowData::Json(to_json(document.root_element())),
+                        Err(error) => FlowData::Error(format!("Failed to parse xml: {}", error)),
+                    },
+                    FlowData::Error(string) => FlowData::Error(string),
+                    _ => FlowData::Error("Unknown data".to_string()),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("xml-parse"),
+            attributes: vec![],
+        }
+    }
+}
+
+pub struct XmlParseBuilder {}
+
+impl NodeBuilder for XmlParseBuilder {
+    fn build(&self, id: i64, name: &str, _entry: &Yaml) -> Option<NodeRef> {
+        if name == "xml-parse" {
+            return Some(Rc::new(RefCell::new(XmlParse {
+                id: id,
+                input: None,
+            })));
+        }
+        None
+    }
+}
@@ -0,0 +1,161 @@
+extern crate json;
+
+use yaml_rust::{Yaml, YamlLoader};
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use BoolFieldData;
+use FlowData;
+
+fn to_json(value: &Yaml) -> Result<json::JsonValue, String> {
+    match *value {
+        Yaml::Null => Ok(json::JsonValue::Null),
+        Yaml::Boolean(boolean) => Ok(json::JsonValue::Boolean(boolean)),
+        Yaml::Integer(int) => Ok(json::JsonValue::from(int)),
+        Yaml::Real(ref text) => match value.as_f64() {
+            Some(float) if float.is_finite() => Ok(json::JsonValue::from(float)),
+            _ => Err(format!("Cannot represent '{}' in json", text)),
+        },
+        Yaml::String(ref string) => Ok(json::JsonValue::String(string.clone())),
+        Yaml::Array(ref array) => array
+            .iter()
+            .map(to_json)
+            .collect::<Result<Vec<_>, _>>()
+            .map(json::JsonValue::Array),
+        Yaml::Hash(ref hash) => {
+            let mut object = json::object::Object::new();
+            for (key, child) in hash.iter() {
+                // Json only allows string keys so scalars are converted
+                let key = match *key {
+                    Yaml::String(ref string) => string.clone(),
+                    Yaml::Integer(int) => int.to_string(),
+                    Yaml::Real(ref text) => text.clone(),
+                    Yaml::Boolean(boolean) => boolean.to_string(),
+                    Yaml::Null => String::from("null"),
+                    _ => return Err(format!("Unsupported yaml key: {:?}", key)),
+                };
+                object.insert(key.as_str(), to_json(child)?);
+            }
+            Ok(json::JsonValue::Object(object))
+        }
+        Yaml::Alias(_) => Err("Yaml aliases are not supported".to_string()),
+        Yaml::BadValue => Err("Invalid yaml value".to_string()),
+    }
+}
+
+pub struct YamlParse {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub multi_document: bool,
+}
+
+impl YamlParse {
+    fn parse(&self, text: &str) -> Result<json::JsonValue, String> {
+        let documents = match YamlLoader::load_from_str(text) {
+            Ok(documents) => documents,
+            Err(error) => return Err(format!("Failed to parse yaml: {}", error)),
+        };
+
+        let values = documents
+            .iter()
+            .map(to_json)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if self.multi_document {
+            return Ok(json::JsonValue::Array(values));
+        }
+
+        match values.len() {
+            0 => Ok(json::JsonValue::Null),
+            1 => Ok(values.into_iter().next().unwrap()),
+            count => Err(format!(
+                "Found {} yaml documents, enable multi-document to read them all",
+                count
+            )),
+        }
+    }
+}
+
+impl Node for YamlParse {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                return match content {
+                    FlowData::String(text) => match self.parse(&text) {
+                        Ok(data) => FlowData::Json(data),
+                        Err(error) => FlowData::Error(error),
+                    },
+                    FlowData::Error(string) => FlowData::Error(string),
+                    _ => FlowData::Error("Unknown data".to_string()),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::BoolField(BoolFieldData {
+            label: String::from("Multiple documents"),
+            field: String::from("multi-document"),
+        })
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        if field == "multi-document" {
+            return NodeUIData::BoolData(self.multi_document);
+        }
+        NodeUIData::None
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("multi-document", NodeUIData::BoolData(value)) => {
+                self.multi_document = value;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("yaml-parse"),
+            attributes: vec![
+                SpecAttribute::Bool(String::from("multi-document"), self.multi_document),
+            ],
+        }
+    }
+}
+
+pub struct YamlParseBuilder {}
+
+impl NodeBuilder for YamlParseBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "yaml-parse" {
+            return Some(Rc::new(RefCell::new(YamlParse {
+                id: id,
+                input: None,
+                multi_document: entry["multi-document"].as_bool().unwrap_or(false),
+            })));
+        }
+        None
+    }
+}
@@ -0,0 +1,146 @@
+extern crate json;
+
+use yaml_rust::Yaml;
+use yaml_rust::yaml::Hash;
+use yaml_rust::emitter::YamlEmitter;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use Spec;
+use SpecAttribute;
+use Node;
+use NodeRef;
+use NodeBuilder;
+use NodeUI;
+use NodeUIData;
+use BoolFieldData;
+use FlowData;
+
+fn to_yaml(value: &json::JsonValue) -> Yaml {
+    match *value {
+        json::JsonValue::Null => Yaml::Null,
+        json::JsonValue::Boolean(boolean) => Yaml::Boolean(boolean),
+        json::JsonValue::Number(_) => match value.as_i64() {
+            Some(int) => Yaml::Integer(int),
+            None => Yaml::Real(value.dump()),
+        },
+        json::JsonValue::Short(ref short) => Yaml::String(short.to_string()),
+        json::JsonValue::String(ref string) => Yaml::String(string.clone()),
+        json::JsonValue::Array(ref array) => Yaml::Array(array.iter().map(to_yaml).collect()),
+        json::JsonValue::Object(ref object) => {
+            let mut hash = Hash::new();
+            for (key, child) in object.iter() {
+                hash.insert(Yaml::String(key.to_string()), to_yaml(child));
+            }
+            Yaml::Hash(hash)
+        }
+    }
+}
+
+pub struct YamlStringify {
+    pub id: i64,
+    pub input: Option<Rc<RefCell<Node>>>,
+    pub multi_document: bool,
+}
+
+impl YamlStringify {
+    fn stringify(&self, data: &json::JsonValue) -> Result<String, String> {
+        // Each member of an array is written as its own document when multi-document is set
+        let documents = if self.multi_document && data.is_array() {
+            data.members().map(to_yaml).collect()
+        } else {
+            vec![to_yaml(data)]
+        };
+
+        let mut output = String::new();
+        for document in documents {
+            let mut buffer = String::new();
+            {
+                let mut emitter = YamlEmitter::new(&mut buffer);
+                if let Err(error) = emitter.dump(&document) {
+                    return Err(format!("Failed to convert to yaml: {:?}", error));
+                }
+            }
+            output.push_str(buffer.as_str());
+            output.push('\n');
+        }
+        Ok(output)
+    }
+}
+
+impl Node for YamlStringify {
+    fn id(&self) -> i64 {
+        self.id
+    }
+
+    fn pull(&mut self) -> FlowData {
+        match self.input {
+            None => return FlowData::Error("No input".to_string()),
+            Some(ref mut input) => {
+                let content = input.borrow_mut().pull();
+
+                return match content {
+                    FlowData::Json(data) => match self.stringify(&data) {
+                        Ok(string) => FlowData::String(string),
+                        Err(error) => FlowData::Error(error),
+                    },
+                    FlowData::Error(string) => FlowData::Error(string),
+                    _ => FlowData::Error("Unknown data".to_string()),
+                };
+            }
+        }
+    }
+
+    fn set_input(&mut self, node: Option<Rc<RefCell<Node>>>, _index: Option<i64>) -> () {
+        self.input = node;
+    }
+
+    fn get_ui(&self) -> NodeUI {
+        NodeUI::BoolField(BoolFieldData {
+            label: String::from("Multiple documents"),
+            field: String::from("multi-document"),
+        })
+    }
+
+    fn get_value(&self, field: &String) -> NodeUIData {
+        if field == "multi-document" {
+            return NodeUIData::BoolData(self.multi_document);
+        }
+        NodeUIData::None
+    }
+
+    fn set_value(&mut self, field: &String, data: NodeUIData) {
+        match (field.as_ref(), data) {
+            ("multi-document", NodeUIData::BoolData(value)) => {
+                self.multi_document = value;
+            }
+            _ => {}
+        }
+    }
+
+    fn get_spec(&self) -> Spec {
+        Spec {
+            id: self.id,
+            type_: String::from("yaml-stringify"),
+            attributes: vec![
+                SpecAttribute::Bool(String::from("multi-document"), self.multi_document),
+            ],
+        }
+    }
+}
+
+pub struct YamlStringifyBuilder {}
+
+impl NodeBuilder for YamlStringifyBuilder {
+    fn build(&self, id: i64, name: &str, entry: &Yaml) -> Option<NodeRef> {
+        if name == "yaml-stringify" {
+            return Some(Rc::new(RefCell::new(YamlStringify {
+                id: id,
+                input: None,
+                multi_document: entry["multi-document"].as_bool().unwrap_or(false),
+            })));
+        }
+        None
+    }
+}
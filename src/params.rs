@@ -44,7 +44,7 @@ pub struct Params {
     pub connect_node: Option<Rc<RefCell<gui_node::GuiNodeData>>>,
     pub node_map: HashMap<i64, Rc<RefCell<Node>>>,
     pub current_connection: Option<conrod::position::Point>,
-    pub connections: HashMap<(i64, i64), Connection>,
+    pub connections: HashMap<(i64, i64, i64), Connection>,
     pub selected_nodes: Vec<conrod::widget::id::Id>,
     pub command_line: CommandLine,
     pub interaction_mode: InteractionMode,